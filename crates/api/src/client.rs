@@ -1,13 +1,65 @@
 use std::time::Duration;
 
-use cynic::{http::CynicReqwestError, GraphQlResponse};
+use cynic::GraphQlResponse;
+use futures::{Stream, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+
+use crate::{
+    error::Error,
+    pagination::{PaginatedQuery, MAX_PAGE_SIZE},
+    rate_limit::RateLimitInfo,
+    retry::RetryPolicy,
+};
 
 const ENDPOINT: &str = "https://api.linear.app/graphql";
 
+/// Error code used by Linear in GraphQL error extensions for rate limiting.
+const RATE_LIMITED_CODE: &str = "RATELIMITED";
+
 pub struct Client {
     client: reqwest::Client,
     endpoint: url::Url,
     token: String,
+    retry: RetryPolicy,
+}
+
+/// Linear-specific `extensions` of a GraphQL error.
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorExtensions {
+    pub code: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub user_error: Option<bool>,
+    pub user_presentable_message: Option<String>,
+}
+
+/// A GraphQL response together with HTTP level metadata.
+#[derive(Debug)]
+pub struct Response<T> {
+    pub status: StatusCode,
+    pub rate_limit: RateLimitInfo,
+    pub response: GraphQlResponse<T, ErrorExtensions>,
+}
+
+impl<T> Response<T> {
+    /// Returns true if Linear rejected the request due to rate limiting.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS
+            || self.response.errors.iter().flatten().any(|err| {
+                err.extensions.as_ref().and_then(|ext| ext.code.as_deref())
+                    == Some(RATE_LIMITED_CODE)
+            })
+    }
+
+    /// Unwrap the response data, converting GraphQL errors into [`Error`].
+    pub fn into_data(self) -> Result<T, Error> {
+        let errors = self.response.errors.unwrap_or_default();
+        if !errors.is_empty() {
+            return Err(Error::from_graphql(errors, &self.rate_limit));
+        }
+        self.response.data.ok_or(Error::NoData)
+    }
 }
 
 impl Client {
@@ -16,6 +68,7 @@ impl Client {
             client,
             endpoint: ENDPOINT.parse().unwrap(),
             token,
+            retry: RetryPolicy::default(),
         }
     }
 
@@ -27,10 +80,78 @@ impl Client {
         Self::new(client, token)
     }
 
+    /// Replace the retry policy.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     pub async fn run<ResponseData, Vars>(
         &self,
         operation: cynic::Operation<ResponseData, Vars>,
-    ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+    ) -> Result<GraphQlResponse<ResponseData, ErrorExtensions>, Error>
+    where
+        Vars: serde::Serialize + std::fmt::Debug,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        self.execute(operation).await.map(|res| res.response)
+    }
+
+    /// Run an operation and return the response data.
+    ///
+    /// GraphQL errors are converted into the matching [`Error`] variant.
+    pub async fn query<ResponseData, Vars>(
+        &self,
+        operation: cynic::Operation<ResponseData, Vars>,
+    ) -> Result<ResponseData, Error>
+    where
+        Vars: serde::Serialize + std::fmt::Debug,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        self.execute(operation).await?.into_data()
+    }
+
+    /// Run an operation and return the response along with the rate limit
+    /// information sent by Linear.
+    ///
+    /// Rate limited requests and transient failures are retried according to
+    /// the configured [`RetryPolicy`].
+    pub async fn execute<ResponseData, Vars>(
+        &self,
+        operation: cynic::Operation<ResponseData, Vars>,
+    ) -> Result<Response<ResponseData>, Error>
+    where
+        Vars: serde::Serialize + std::fmt::Debug,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let mut attempt = 0;
+        loop {
+            let outcome = self.send(&operation).await;
+
+            let delay = match &outcome {
+                Ok(res) if res.is_rate_limited() => {
+                    Some(self.retry.rate_limit_delay(attempt, &res.rate_limit))
+                }
+                Ok(res) if res.status.is_server_error() => Some(self.retry.backoff(attempt)),
+                Ok(_) => None,
+                Err(err) if err.is_transient() => Some(self.retry.backoff(attempt)),
+                Err(_) => None,
+            };
+
+            match delay {
+                Some(delay) if attempt < self.retry.max_retries => {
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                _ => return outcome,
+            }
+        }
+    }
+
+    async fn send<ResponseData, Vars>(
+        &self,
+        operation: &cynic::Operation<ResponseData, Vars>,
+    ) -> Result<Response<ResponseData>, Error>
     where
         Vars: serde::Serialize + std::fmt::Debug,
         ResponseData: serde::de::DeserializeOwned + 'static,
@@ -39,35 +160,97 @@ impl Client {
             .client
             .post(self.endpoint.clone())
             .header(reqwest::header::AUTHORIZATION, &self.token)
-            .json(&operation)
+            .json(operation)
             .send()
-            .await
-            .map_err(CynicReqwestError::ReqwestError)?;
+            .await?;
 
-        // if let Some(value) = response
-        //     .headers()
-        //     .get("X-RateLimit-Requests-Remaining")
-        //     .and_then(|x| x.to_str().ok())
-        // {
-        // }
+        let rate_limit = RateLimitInfo::from_headers(response.headers());
 
         let status = response.status();
-        if !status.is_success() {
-            let text = response.text().await;
-            let text = match text {
-                Ok(text) => text,
-                Err(e) => return Err(CynicReqwestError::ReqwestError(e)),
-            };
+        let response = if !status.is_success() {
+            let text = response.text().await?;
 
             let Ok(deserred) = serde_json::from_str(&text) else {
-                let response = CynicReqwestError::ErrorResponse(status, text);
-                return Err(response);
+                let err = match status {
+                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                        Error::Authentication { message: text }
+                    }
+                    _ => Error::Http { status, body: text },
+                };
+                return Err(err);
             };
 
-            Ok(deserred)
+            deserred
         } else {
-            let json = response.json().await;
-            json.map_err(CynicReqwestError::ReqwestError)
+            response.json().await?
+        };
+
+        Ok(Response {
+            status,
+            rate_limit,
+            response,
+        })
+    }
+
+    /// Run a paginated query, following the connection cursor until either
+    /// all pages are exhausted or `limit` nodes have been returned.
+    ///
+    /// The `first` and `after` fields of `vars` are managed by the paginator.
+    pub fn paginate<Q>(
+        &self,
+        vars: Q::Variables,
+        limit: Option<usize>,
+    ) -> impl Stream<Item = Result<Q::Node, Error>> + '_
+    where
+        Q: PaginatedQuery,
+    {
+        self.paginate_pages::<Q>(vars, limit)
+            .map_ok(|nodes| futures::stream::iter(nodes.into_iter().map(Ok)))
+            .try_flatten()
+            .take(limit.unwrap_or(usize::MAX))
+    }
+
+    /// Like [`Self::paginate`], but yields whole pages instead of single nodes.
+    pub fn paginate_pages<Q>(
+        &self,
+        vars: Q::Variables,
+        limit: Option<usize>,
+    ) -> impl Stream<Item = Result<Vec<Q::Node>, Error>> + '_
+    where
+        Q: PaginatedQuery,
+    {
+        struct State<V> {
+            vars: V,
+            cursor: Option<String>,
+            remaining: Option<usize>,
+            done: bool,
         }
+
+        let state = State {
+            vars,
+            cursor: None,
+            remaining: limit,
+            done: false,
+        };
+
+        futures::stream::try_unfold(state, move |mut state| async move {
+            if state.done || state.remaining == Some(0) {
+                return Ok(None);
+            }
+
+            let first = state.remaining.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+            let mut vars = state.vars.clone();
+            Q::set_page(&mut vars, first as i32, state.cursor.take());
+
+            let (page_info, nodes) = self.query(Q::build(vars)).await?.into_page();
+
+            state.cursor = page_info.end_cursor;
+            state.done = !page_info.has_next_page || state.cursor.is_none();
+            if let Some(remaining) = &mut state.remaining {
+                *remaining = remaining.saturating_sub(nodes.len());
+            }
+
+            Ok(Some((nodes, state)))
+        })
     }
 }
@@ -0,0 +1,267 @@
+use cynic::GraphQlError;
+use reqwest::StatusCode;
+
+use crate::{client::ErrorExtensions, rate_limit::RateLimitInfo};
+
+/// Errors returned by [`crate::Client`].
+#[derive(Debug)]
+pub enum Error {
+    /// The API token is missing, invalid or lacks the required permissions.
+    Authentication { message: String },
+    /// The requested entity does not exist.
+    NotFound { message: String },
+    /// Linear rejected the request due to rate limiting.
+    RateLimited {
+        message: String,
+        rate_limit: Box<RateLimitInfo>,
+    },
+    /// The request was rejected because of invalid input.
+    Validation {
+        message: String,
+        /// Human readable message provided by Linear in
+        /// `extensions.userPresentableMessage`.
+        user_message: Option<String>,
+    },
+    /// Any other error reported by the GraphQL API.
+    GraphQl(Vec<GraphQlError<ErrorExtensions>>),
+    /// A non-success HTTP response without a GraphQL error body.
+    Http { status: StatusCode, body: String },
+    /// The request could not be sent or the response could not be received.
+    Network(reqwest::Error),
+    /// The response did not match the expected schema.
+    Schema(String),
+    /// The response contained neither data nor errors.
+    NoData,
+}
+
+impl Error {
+    /// Classify the errors of a GraphQL response.
+    ///
+    /// Only the first error is used to determine the kind, all errors are
+    /// kept for [`Error::GraphQl`].
+    pub fn from_graphql(
+        errors: Vec<GraphQlError<ErrorExtensions>>,
+        rate_limit: &RateLimitInfo,
+    ) -> Self {
+        if errors.is_empty() {
+            return Self::NoData;
+        }
+
+        let first = &errors[0];
+        let ext = first.extensions.clone().unwrap_or_default();
+        let code = ext.code.as_deref().unwrap_or_default().to_ascii_uppercase();
+        let kind = ext
+            .type_
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let message = first.message.clone();
+
+        if code == "RATELIMITED" || kind == "ratelimited" {
+            Self::RateLimited {
+                message,
+                rate_limit: Box::new(rate_limit.clone()),
+            }
+        } else if code == "AUTHENTICATION_ERROR"
+            || code == "FORBIDDEN"
+            || kind == "authentication error"
+            || kind == "forbidden"
+        {
+            Self::Authentication {
+                message: ext.user_presentable_message.unwrap_or(message),
+            }
+        } else if message.to_ascii_lowercase().starts_with("entity not found") {
+            Self::NotFound {
+                message: ext.user_presentable_message.unwrap_or(message),
+            }
+        } else if code == "INPUT_ERROR"
+            || code == "INVALID_INPUT"
+            || kind == "invalid input"
+            || kind == "user error"
+            || ext.user_error == Some(true)
+        {
+            Self::Validation {
+                message,
+                user_message: ext.user_presentable_message,
+            }
+        } else {
+            Self::GraphQl(errors)
+        }
+    }
+
+    /// Errors that may succeed when retried.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::RateLimited { .. } => true,
+            Self::Network(err) => err.is_connect() || err.is_timeout(),
+            Self::Http { status, .. } => {
+                *status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }
+            Self::Authentication { .. }
+            | Self::NotFound { .. }
+            | Self::Validation { .. }
+            | Self::GraphQl(_)
+            | Self::Schema(_)
+            | Self::NoData => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Authentication { message } => write!(f, "authentication failed: {message}"),
+            Self::NotFound { message } => write!(f, "not found: {message}"),
+            Self::RateLimited { message, .. } => write!(f, "rate limited: {message}"),
+            Self::Validation {
+                message,
+                user_message,
+            } => write!(f, "{}", user_message.as_deref().unwrap_or(message)),
+            Self::GraphQl(errors) => {
+                let messages = errors
+                    .iter()
+                    .map(|e| e.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "query failed: {messages}")
+            }
+            Self::Http { status, body } => {
+                write!(f, "request failed with status {status}: {body}")
+            }
+            // The reqwest error is the source, so it's not repeated here.
+            Self::Network(_) => write!(f, "network error"),
+            Self::Schema(msg) => write!(f, "invalid response: {msg}"),
+            Self::NoData => write!(f, "no data in response"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Network(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_decode() {
+            Self::Schema(err.to_string())
+        } else {
+            Self::Network(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Classify the errors of a GraphQL response body.
+    fn classify(errors: serde_json::Value) -> Error {
+        let errors = serde_json::from_value(errors).unwrap();
+        Error::from_graphql(errors, &RateLimitInfo::default())
+    }
+
+    #[test]
+    fn authentication() {
+        let err = classify(json!([{
+            "message": "Authentication required, not authenticated",
+            "extensions": {
+                "type": "authentication error",
+                "code": "AUTHENTICATION_ERROR",
+                "userPresentableMessage": "You need to authenticate to access this operation."
+            }
+        }]));
+        assert!(matches!(err, Error::Authentication { .. }));
+        assert_eq!(
+            err.to_string(),
+            "authentication failed: You need to authenticate to access this operation."
+        );
+
+        let err = classify(json!([{
+            "message": "Forbidden",
+            "extensions": { "code": "FORBIDDEN" }
+        }]));
+        assert!(matches!(err, Error::Authentication { .. }));
+    }
+
+    #[test]
+    fn not_found() {
+        let err = classify(json!([{
+            "message": "Entity not found: Issue",
+            "path": ["issue"],
+            "extensions": {
+                "type": "invalid input",
+                "code": "INPUT_ERROR",
+                "userPresentableMessage": "Could not find referenced Issue."
+            }
+        }]));
+        assert!(matches!(err, Error::NotFound { .. }));
+        assert_eq!(
+            err.to_string(),
+            "not found: Could not find referenced Issue."
+        );
+    }
+
+    #[test]
+    fn rate_limited() {
+        let err = classify(json!([{
+            "message": "Rate limit exceeded",
+            "extensions": { "code": "RATELIMITED" }
+        }]));
+        assert!(matches!(err, Error::RateLimited { .. }));
+        assert!(err.is_transient());
+
+        let err = classify(json!([{
+            "message": "Too many requests",
+            "extensions": { "type": "Ratelimited" }
+        }]));
+        assert!(matches!(err, Error::RateLimited { .. }));
+    }
+
+    #[test]
+    fn validation() {
+        let err = classify(json!([{
+            "message": "Argument Validation Error",
+            "extensions": {
+                "type": "invalid input",
+                "code": "INVALID_INPUT",
+                "userError": true,
+                "userPresentableMessage": "title must be shorter than or equal to 255 characters"
+            }
+        }]));
+        assert!(matches!(err, Error::Validation { .. }));
+        assert!(!err.is_transient());
+        assert_eq!(
+            err.to_string(),
+            "title must be shorter than or equal to 255 characters"
+        );
+
+        let err = classify(json!([{
+            "message": "Estimate is not valid for this team",
+            "extensions": { "userError": true }
+        }]));
+        assert!(matches!(err, Error::Validation { .. }));
+        assert_eq!(err.to_string(), "Estimate is not valid for this team");
+    }
+
+    #[test]
+    fn other_errors() {
+        let err = classify(json!([
+            { "message": "Internal server error" },
+            { "message": "Something else" }
+        ]));
+        assert!(matches!(err, Error::GraphQl(ref errors) if errors.len() == 2));
+        assert_eq!(
+            err.to_string(),
+            "query failed: Internal server error, Something else"
+        );
+
+        assert!(matches!(classify(json!([])), Error::NoData));
+    }
+}
@@ -1,4 +1,9 @@
 pub mod client;
+pub mod error;
+pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
 pub mod schema;
 
 pub use client::Client;
+pub use error::Error;
@@ -0,0 +1,23 @@
+use cynic::QueryBuilder;
+
+use crate::schema::PageInfo;
+
+/// Maximum page size accepted by the Linear API.
+pub const MAX_PAGE_SIZE: usize = 250;
+
+/// A query that returns a single paginated connection.
+///
+/// Implemented for the list queries in [`crate::schema`], and used by
+/// [`crate::Client::paginate`] to follow `endCursor`/`hasNextPage`.
+pub trait PaginatedQuery:
+    QueryBuilder<Self::Variables> + serde::de::DeserializeOwned + 'static
+{
+    type Variables: serde::Serialize + std::fmt::Debug + Clone;
+    type Node;
+
+    /// Set the page size and the cursor to continue after.
+    fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>);
+
+    /// Split the response into the page info and the returned nodes.
+    fn into_page(self) -> (PageInfo, Vec<Self::Node>);
+}
@@ -0,0 +1,155 @@
+use reqwest::header::HeaderMap;
+use time::OffsetDateTime;
+
+/// Rate limit and query complexity information returned by Linear in the
+/// `X-RateLimit-*` and `X-Complexity` response headers.
+///
+/// All fields are optional, because Linear does not send every header on
+/// every response.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Maximum number of requests allowed in the current window.
+    pub requests_limit: Option<u64>,
+    /// Remaining number of requests in the current window.
+    pub requests_remaining: Option<u64>,
+    /// When the request window resets.
+    pub requests_reset: Option<OffsetDateTime>,
+    /// Complexity of the query that was just executed.
+    pub complexity: Option<u64>,
+    /// Maximum complexity points allowed in the current window.
+    pub complexity_limit: Option<u64>,
+    /// Remaining complexity points in the current window.
+    pub complexity_remaining: Option<u64>,
+    /// When the complexity window resets.
+    pub complexity_reset: Option<OffsetDateTime>,
+}
+
+impl RateLimitInfo {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            requests_limit: header_u64(headers, "X-RateLimit-Requests-Limit"),
+            requests_remaining: header_u64(headers, "X-RateLimit-Requests-Remaining"),
+            requests_reset: header_timestamp(headers, "X-RateLimit-Requests-Reset"),
+            complexity: header_u64(headers, "X-Complexity"),
+            complexity_limit: header_u64(headers, "X-RateLimit-Complexity-Limit"),
+            complexity_remaining: header_u64(headers, "X-RateLimit-Complexity-Remaining"),
+            complexity_reset: header_timestamp(headers, "X-RateLimit-Complexity-Reset"),
+        }
+    }
+
+    /// Returns true if either the request or the complexity budget is used up.
+    pub fn is_exhausted(&self) -> bool {
+        self.requests_remaining == Some(0) || self.complexity_remaining == Some(0)
+    }
+
+    /// The earliest point in time at which an exhausted budget resets.
+    pub fn reset_at(&self) -> Option<OffsetDateTime> {
+        let requests = self
+            .requests_reset
+            .filter(|_| self.requests_remaining == Some(0));
+        let complexity = self
+            .complexity_reset
+            .filter(|_| self.complexity_remaining == Some(0));
+
+        match (requests, complexity) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b).or(self.requests_reset).or(self.complexity_reset),
+        }
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Linear sends reset times as UTC epoch milliseconds.
+fn header_timestamp(headers: &HeaderMap, name: &str) -> Option<OffsetDateTime> {
+    let millis = header_u64(headers, name)?;
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn at(millis: i128) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp_nanos(millis * 1_000_000).unwrap()
+    }
+
+    #[test]
+    fn parses_headers() {
+        let info = RateLimitInfo::from_headers(&headers(&[
+            ("x-ratelimit-requests-limit", "1500"),
+            ("x-ratelimit-requests-remaining", " 1499 "),
+            ("x-ratelimit-requests-reset", "1767225600000"),
+            ("x-complexity", "42"),
+            ("x-ratelimit-complexity-limit", "250000"),
+            ("x-ratelimit-complexity-remaining", "249958"),
+            ("x-ratelimit-complexity-reset", "1767225601500"),
+        ]));
+        assert_eq!(
+            info,
+            RateLimitInfo {
+                requests_limit: Some(1500),
+                requests_remaining: Some(1499),
+                requests_reset: Some(at(1_767_225_600_000)),
+                complexity: Some(42),
+                complexity_limit: Some(250_000),
+                complexity_remaining: Some(249_958),
+                complexity_reset: Some(at(1_767_225_601_500)),
+            }
+        );
+        assert!(!info.is_exhausted());
+    }
+
+    #[test]
+    fn ignores_missing_and_garbage_headers() {
+        assert_eq!(
+            RateLimitInfo::from_headers(&HeaderMap::new()),
+            RateLimitInfo::default()
+        );
+
+        let info = RateLimitInfo::from_headers(&headers(&[
+            ("x-ratelimit-requests-limit", "many"),
+            ("x-ratelimit-requests-remaining", "-1"),
+            ("x-ratelimit-requests-reset", "tomorrow"),
+            ("x-complexity", ""),
+            ("x-ratelimit-complexity-reset", "99999999999999999999"),
+        ]));
+        assert_eq!(info, RateLimitInfo::default());
+    }
+
+    #[test]
+    fn resets_when_the_exhausted_budget_does() {
+        let mut info = RateLimitInfo {
+            requests_remaining: Some(0),
+            requests_reset: Some(at(2_000)),
+            complexity_remaining: Some(10),
+            complexity_reset: Some(at(1_000)),
+            ..Default::default()
+        };
+        assert!(info.is_exhausted());
+        assert_eq!(info.reset_at(), Some(at(2_000)));
+
+        // Both exhausted: wait for the later one.
+        info.complexity_remaining = Some(0);
+        info.complexity_reset = Some(at(3_000));
+        assert_eq!(info.reset_at(), Some(at(3_000)));
+
+        // Nothing exhausted (eg: a RATELIMITED error): any known reset.
+        info.requests_remaining = None;
+        info.complexity_remaining = None;
+        assert_eq!(info.reset_at(), Some(at(2_000)));
+        assert_eq!(RateLimitInfo::default().reset_at(), None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+
+use crate::rate_limit::RateLimitInfo;
+
+/// Controls how [`crate::Client`] retries failed requests.
+///
+/// Requests are retried when Linear reports a rate limit (HTTP 429 or a
+/// `RATELIMITED` GraphQL error), on HTTP 5xx responses and on connection
+/// failures or timeouts.
+///
+/// Note that mutations are retried as well, so a mutation that failed with a
+/// transient error after reaching the server may be applied twice.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    ///
+    /// Set to `0` to disable retries.
+    pub max_retries: u32,
+    /// Delay before the first retry. Doubled for each subsequent retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for a single delay.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Exponential backoff delay for the given (zero-based) retry attempt.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Delay for a rate limited request.
+    ///
+    /// Waits until the rate limit window resets if Linear told us when that
+    /// happens, and falls back to exponential backoff otherwise.
+    pub fn rate_limit_delay(&self, attempt: u32, info: &RateLimitInfo) -> Duration {
+        let until_reset = info.reset_at().and_then(|reset| {
+            let remaining = reset - OffsetDateTime::now_utc();
+            Duration::try_from(remaining).ok()
+        });
+
+        match until_reset {
+            Some(delay) if !delay.is_zero() => delay.min(self.max_backoff),
+            _ => self.backoff(attempt),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(3),
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let delays = (0..5).map(|a| policy().backoff(a)).collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [
+                Duration::from_millis(500),
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(3),
+                Duration::from_secs(3),
+            ]
+        );
+        assert_eq!(policy().backoff(u32::MAX), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn rate_limit_waits_for_the_reset() {
+        let info = |reset: time::Duration| RateLimitInfo {
+            requests_remaining: Some(0),
+            requests_reset: Some(OffsetDateTime::now_utc() + reset),
+            ..Default::default()
+        };
+
+        let delay = policy().rate_limit_delay(0, &info(time::Duration::seconds(2)));
+        assert!(delay > Duration::from_secs(1) && delay <= Duration::from_secs(2));
+
+        // Capped by the maximum backoff.
+        let delay = policy().rate_limit_delay(0, &info(time::Duration::hours(1)));
+        assert_eq!(delay, Duration::from_secs(3));
+
+        // A reset in the past or no reset at all falls back to backoff.
+        let delay = policy().rate_limit_delay(1, &info(time::Duration::seconds(-5)));
+        assert_eq!(delay, Duration::from_secs(1));
+        let delay = policy().rate_limit_delay(2, &RateLimitInfo::default());
+        assert_eq!(delay, Duration::from_secs(2));
+    }
+}
@@ -10,6 +10,15 @@ impl DateTime {
     }
 }
 
+#[derive(cynic::Scalar, Debug, Clone)]
+#[cynic(graphql_type = "UUID")]
+pub struct Uuid(pub String);
+
+/// Arbitrary JSON object, eg: search result metadata.
+#[derive(cynic::Scalar, Debug, Clone)]
+#[cynic(graphql_type = "JSONObject")]
+pub struct JsonObject(pub serde_json::Value);
+
 #[derive(cynic::Enum, Clone, Copy, Debug)]
 pub enum PaginationOrderBy {
     #[cynic(rename = "createdAt")]
@@ -27,6 +36,52 @@ pub struct User {
     pub is_me: bool,
 }
 
+/// An input value that can be explicitly set to `null`.
+///
+/// Used for update inputs, where a missing field leaves the value unchanged
+/// and [`Nullable::Null`] clears it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Nullable<T> {
+    Null,
+    Value(T),
+}
+
+impl<T, TypeLock> cynic::coercions::CoercesTo<TypeLock> for Nullable<T> where
+    T: cynic::coercions::CoercesTo<TypeLock>
+{
+}
+
+impl<T, SchemaType> cynic::schema::IsScalar<SchemaType> for Nullable<T>
+where
+    T: cynic::schema::IsScalar<SchemaType>,
+{
+    type SchemaType = T::SchemaType;
+}
+
+impl<T: serde::Serialize> serde::Serialize for Nullable<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Null => serializer.serialize_none(),
+            Self::Value(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Nullable<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self::Value(value),
+            None => Self::Null,
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
 pub mod viewer {
     use super::{schema, User};
 
@@ -38,18 +93,19 @@ pub mod viewer {
 }
 
 pub mod team_list {
-    use super::{schema, DateTime, PaginationOrderBy};
+    use super::{schema, DateTime, PageInfo, PaginationOrderBy};
 
-    #[derive(cynic::QueryVariables, Debug)]
+    #[derive(cynic::QueryVariables, Clone, Debug)]
     pub struct TeamListVariables {
         pub first: i32,
+        pub after: Option<String>,
         pub order: PaginationOrderBy,
     }
 
     #[derive(cynic::QueryFragment, Debug)]
     #[cynic(graphql_type = "Query", variables = "TeamListVariables")]
     pub struct TeamList {
-        #[arguments(first: $first, orderBy: $order)]
+        #[arguments(first: $first, after: $after, orderBy: $order)]
         pub teams: TeamConnection,
     }
 
@@ -69,18 +125,28 @@ pub mod team_list {
         pub key: String,
     }
 
-    #[derive(cynic::QueryFragment, Debug)]
-    pub struct PageInfo {
-        pub end_cursor: Option<String>,
+    impl crate::pagination::PaginatedQuery for TeamList {
+        type Variables = TeamListVariables;
+        type Node = Team;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.teams.page_info, self.teams.nodes)
+        }
     }
 }
 
 pub mod projects_list {
-    use super::{schema, DateTime, PaginationOrderBy, User};
+    use super::{schema, DateTime, PageInfo, PaginationOrderBy, User};
 
-    #[derive(cynic::QueryVariables, Debug)]
+    #[derive(cynic::QueryVariables, Clone, Debug)]
     pub struct ProjectListVariables {
         pub first: i32,
+        pub after: Option<String>,
         pub include_archived: bool,
         pub oder: PaginationOrderBy,
     }
@@ -88,7 +154,7 @@ pub mod projects_list {
     #[derive(cynic::QueryFragment, Debug)]
     #[cynic(graphql_type = "Query", variables = "ProjectListVariables")]
     pub struct ProjectList {
-        #[arguments(first: $first, orderBy: $oder, includeArchived: $include_archived)]
+        #[arguments(first: $first, after: $after, orderBy: $oder, includeArchived: $include_archived)]
         pub projects: ProjectConnection,
     }
 
@@ -119,18 +185,31 @@ pub mod projects_list {
         pub name: String,
     }
 
-    #[derive(cynic::QueryFragment, Debug)]
-    pub struct PageInfo {
-        pub end_cursor: Option<String>,
+    impl crate::pagination::PaginatedQuery for ProjectList {
+        type Variables = ProjectListVariables;
+        type Node = Project;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.projects.page_info, self.projects.nodes)
+        }
     }
 }
 
 pub mod issue_list {
-    use super::{schema, DateTime, PaginationOrderBy, User};
+    use super::{
+        lookup::{CycleRef, IssueRef, ProjectRef, TeamRef},
+        schema, DateTime, PageInfo, PaginationOrderBy, User,
+    };
 
-    #[derive(cynic::QueryVariables, Debug)]
+    #[derive(cynic::QueryVariables, Clone, Debug)]
     pub struct IssueListVariables {
         pub first: i32,
+        pub after: Option<String>,
         pub order: PaginationOrderBy,
         pub sort: Option<Vec<IssueSortInput>>,
         pub filter: Option<IssueFilter>,
@@ -139,7 +218,7 @@ pub mod issue_list {
     #[derive(cynic::QueryFragment, Debug)]
     #[cynic(graphql_type = "Query", variables = "IssueListVariables")]
     pub struct IssueList {
-        #[arguments(first: $first, sort: $sort, orderBy: $order, filter: $filter)]
+        #[arguments(first: $first, after: $after, sort: $sort, orderBy: $order, filter: $filter)]
         pub issues: IssueConnection,
     }
 
@@ -149,9 +228,18 @@ pub mod issue_list {
         pub nodes: Vec<Issue>,
     }
 
-    #[derive(cynic::QueryFragment, Debug)]
-    pub struct PageInfo {
-        pub end_cursor: Option<String>,
+    impl crate::pagination::PaginatedQuery for IssueList {
+        type Variables = IssueListVariables;
+        type Node = Issue;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.issues.page_info, self.issues.nodes)
+        }
     }
 
     #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
@@ -177,6 +265,11 @@ pub mod issue_list {
         pub triaged_at: Option<DateTime>,
         pub updated_at: DateTime,
         pub url: String,
+        pub team: TeamRef,
+        pub labels: IssueLabelConnection,
+        pub project: Option<ProjectRef>,
+        pub cycle: Option<CycleRef>,
+        pub parent: Option<IssueRef>,
     }
 
     #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
@@ -185,6 +278,18 @@ pub mod issue_list {
         pub color: String,
     }
 
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueLabelConnection {
+        pub nodes: Vec<IssueLabel>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueLabel {
+        pub id: cynic::Id,
+        pub name: String,
+        pub color: String,
+    }
+
     #[derive(cynic::Enum, Clone, Copy, Debug)]
     pub enum PaginationNulls {
         #[cynic(rename = "first")]
@@ -217,88 +322,125 @@ pub mod issue_list {
         Failed,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct IssueSortInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub priority: Option<PrioritySort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub estimate: Option<EstimateSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub title: Option<TitleSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub label: Option<LabelSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub sla_status: Option<SlaStatusSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<CreatedAtSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<UpdatedAtSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub completed_at: Option<CompletedAtSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub due_date: Option<DueDateSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub cycle: Option<CycleSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub milestone: Option<MilestoneSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub assignee: Option<AssigneeSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub project: Option<ProjectSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub team: Option<TeamSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub manual: Option<ManualSort>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub workflow_state: Option<WorkflowStateSort>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct WorkflowStateSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ManualSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct TeamSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct MilestoneSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct UpdatedAtSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct SlaStatusSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct LabelSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct TitleSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct PrioritySort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub no_priority_first: Option<bool>,
     }
 
-    #[derive(cynic::InputObject, Debug, Default)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct IssueFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -333,23 +475,29 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub snoozed_until_at: Option<NullableDateComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub assignee: Option<NullableUserFilter>,
+        pub assignee: Option<Box<NullableUserFilter>>,
         // pub last_applied_template: Option<NullableTemplateFilter>,
         // pub source_metadata: Option<SourceMetadataComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub creator: Option<NullableUserFilter>,
-        // pub parent: Option<NullableIssueFilter>,
+        pub creator: Option<Box<NullableUserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent: Option<Box<NullableIssueFilter>>,
         // pub snoozed_by: Option<NullableUserFilter>,
-        // pub labels: Option<IssueLabelCollectionFilter>,
-        // pub subscribers: Option<UserCollectionFilter>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<TeamFilter>,
+        pub labels: Option<Box<IssueLabelCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub subscribers: Option<Box<UserCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub team: Option<Box<TeamFilter>>,
         // pub project_milestone: Option<NullableProjectMilestoneFilter>,
-        // pub comments: Option<CommentCollectionFilter>,
-        // pub cycle: Option<NullableCycleFilter>,
-        // pub project: Option<NullableProjectFilter>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub state: Option<WorkflowStateFilter>,
+        pub comments: Option<Box<CommentCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub cycle: Option<Box<NullableCycleFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project: Option<Box<NullableProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub state: Option<Box<WorkflowStateFilter>>,
         // pub children: Option<IssueCollectionFilter>,
         // pub attachments: Option<AttachmentCollectionFilter>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -375,40 +523,143 @@ pub mod issue_list {
             }
         }
 
+        pub fn new_or(filters: Vec<Self>) -> Self {
+            Self {
+                or: Some(filters),
+                ..Default::default()
+            }
+        }
+
         pub fn creator_username(name: String) -> Self {
             Self {
-                creator: Some(NullableUserFilter::username(name)),
+                creator: Some(Box::new(NullableUserFilter::username(name))),
                 ..Default::default()
             }
         }
 
         pub fn creator_me() -> Self {
             Self {
-                creator: Some(NullableUserFilter::me()),
+                creator: Some(Box::new(NullableUserFilter::me())),
                 ..Default::default()
             }
         }
 
         pub fn assignee_username(name: String) -> Self {
             Self {
-                assignee: Some(NullableUserFilter::username(name)),
+                assignee: Some(Box::new(NullableUserFilter::username(name))),
                 ..Default::default()
             }
         }
 
         pub fn assignee_me() -> Self {
             Self {
-                assignee: Some(NullableUserFilter::me()),
+                assignee: Some(Box::new(NullableUserFilter::me())),
+                ..Default::default()
+            }
+        }
+
+        pub fn unassigned() -> Self {
+            Self {
+                assignee: Some(Box::new(NullableUserFilter {
+                    null: Some(true),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+        }
+
+        pub fn subscriber(user: UserFilter) -> Self {
+            Self {
+                subscribers: Some(Box::new(UserCollectionFilter {
+                    some: Some(Box::new(user)),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+        }
+
+        /// Issues with a comment by the given user.
+        pub fn commenter(user: UserFilter) -> Self {
+            Self {
+                comments: Some(Box::new(CommentCollectionFilter {
+                    some: Some(Box::new(CommentFilter {
+                        user: Some(Box::new(user)),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+        }
+
+        /// Issues whose description or comments mention a user.
+        ///
+        /// Mentions are stored as markdown links to the user's profile URL.
+        pub fn mentioning(profile_url: String) -> Self {
+            Self::new_or(vec![
+                Self {
+                    description: Some(NullableStringComparator {
+                        contains: Some(profile_url.clone()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Self {
+                    comments: Some(Box::new(CommentCollectionFilter {
+                        some: Some(Box::new(CommentFilter {
+                            body: Some(StringComparator {
+                                contains: Some(profile_url),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                },
+            ])
+        }
+
+        pub fn without_project() -> Self {
+            Self {
+                project: Some(Box::new(NullableProjectFilter {
+                    null: Some(true),
+                    ..Default::default()
+                })),
                 ..Default::default()
             }
         }
 
         pub fn team_key(key: String) -> Self {
             Self {
-                team: Some(TeamFilter {
+                team: Some(Box::new(TeamFilter {
                     key: Some(StringComparator::eq(key)),
                     ..Default::default()
-                }),
+                })),
+                ..Default::default()
+            }
+        }
+
+        /// Issues with a label of the given name.
+        ///
+        /// Labels inside a group can be given as "Group/Label".
+        pub fn label(name: String) -> Self {
+            let mut filter = IssueLabelFilter::default();
+            match name.split_once('/') {
+                Some((group, label)) => {
+                    filter.name = Some(StringComparator::eq_ignore_case(label.trim().to_string()));
+                    filter.parent = Some(Box::new(IssueLabelFilter {
+                        name: Some(StringComparator::eq_ignore_case(group.trim().to_string())),
+                        ..Default::default()
+                    }));
+                }
+                None => filter.name = Some(StringComparator::eq_ignore_case(name)),
+            }
+            Self {
+                labels: Some(Box::new(IssueLabelCollectionFilter {
+                    some: Some(Box::new(filter)),
+                    ..Default::default()
+                })),
                 ..Default::default()
             }
         }
@@ -424,7 +675,7 @@ pub mod issue_list {
         }
     }
 
-    #[derive(cynic::InputObject, Debug, Default)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableUserFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -471,7 +722,7 @@ pub mod issue_list {
         }
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct IssueCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -508,31 +759,31 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub assignee: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub last_applied_template: Option<NullableTemplateFilter>,
+        pub last_applied_template: Option<Box<NullableTemplateFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub source_metadata: Option<SourceMetadataComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub creator: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub parent: Option<NullableIssueFilter>,
+        pub parent: Option<Box<NullableIssueFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub snoozed_by: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub labels: Option<IssueLabelCollectionFilter>,
+        pub labels: Option<Box<IssueLabelCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub subscribers: Option<UserCollectionFilter>,
+        pub subscribers: Option<Box<UserCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<TeamFilter>,
+        pub team: Option<Box<TeamFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub project_milestone: Option<NullableProjectMilestoneFilter>,
+        pub project_milestone: Option<Box<NullableProjectMilestoneFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub comments: Option<CommentCollectionFilter>,
+        pub comments: Option<Box<CommentCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub cycle: Option<NullableCycleFilter>,
+        pub cycle: Option<Box<NullableCycleFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub project: Option<NullableProjectFilter>,
+        pub project: Option<Box<NullableProjectFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub state: Option<WorkflowStateFilter>,
+        pub state: Option<Box<WorkflowStateFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub children: Option<Box<IssueCollectionFilter>>,
         // #[cynic(skip_serializing_if = "Option::is_none")]
@@ -550,9 +801,9 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub sla_status: Option<SlaStatusComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub reactions: Option<ReactionCollectionFilter>,
+        pub reactions: Option<Box<ReactionCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub needs: Option<CustomerNeedCollectionFilter>,
+        pub needs: Option<Box<CustomerNeedCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub some: Option<Box<IssueFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -564,7 +815,7 @@ pub mod issue_list {
         // pub or: Option<Vec<IssueCollectionFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableIssueFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -601,7 +852,7 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub assignee: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub last_applied_template: Option<NullableTemplateFilter>,
+        pub last_applied_template: Option<Box<NullableTemplateFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub source_metadata: Option<SourceMetadataComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -611,21 +862,21 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub snoozed_by: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub labels: Option<IssueLabelCollectionFilter>,
+        pub labels: Option<Box<IssueLabelCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub subscribers: Option<UserCollectionFilter>,
+        pub subscribers: Option<Box<UserCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<TeamFilter>,
+        pub team: Option<Box<TeamFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub project_milestone: Option<NullableProjectMilestoneFilter>,
+        pub project_milestone: Option<Box<NullableProjectMilestoneFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub comments: Option<CommentCollectionFilter>,
+        pub comments: Option<Box<CommentCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub cycle: Option<NullableCycleFilter>,
+        pub cycle: Option<Box<NullableCycleFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub project: Option<NullableProjectFilter>,
+        pub project: Option<Box<NullableProjectFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub state: Option<WorkflowStateFilter>,
+        pub state: Option<Box<WorkflowStateFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub children: Option<Box<IssueCollectionFilter>>,
         // #[cynic(skip_serializing_if = "Option::is_none")]
@@ -643,9 +894,9 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub sla_status: Option<SlaStatusComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub reactions: Option<ReactionCollectionFilter>,
+        pub reactions: Option<Box<ReactionCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub needs: Option<CustomerNeedCollectionFilter>,
+        pub needs: Option<Box<CustomerNeedCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub null: Option<bool>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -654,8 +905,9 @@ pub mod issue_list {
         pub or: Option<Vec<NullableIssueFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct SlaStatusComparator {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<SlaStatus>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub neq: Option<SlaStatus>,
@@ -667,7 +919,7 @@ pub mod issue_list {
         pub null: Option<bool>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct WorkflowStateFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -684,7 +936,7 @@ pub mod issue_list {
         #[cynic(rename = "type", skip_serializing_if = "Option::is_none")]
         pub type_: Option<StringComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<TeamFilter>,
+        pub team: Option<Box<TeamFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub issues: Option<Box<IssueCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -693,7 +945,7 @@ pub mod issue_list {
         pub or: Option<Vec<WorkflowStateFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableCycleFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -724,7 +976,7 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub is_past: Option<BooleanComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<TeamFilter>,
+        pub team: Option<Box<TeamFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub issues: Option<Box<IssueCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -735,7 +987,7 @@ pub mod issue_list {
         pub or: Option<Vec<NullableCycleFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableProjectMilestoneFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -755,7 +1007,7 @@ pub mod issue_list {
         pub or: Option<Vec<NullableProjectMilestoneFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct IssueLabelCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -768,22 +1020,22 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub creator: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<NullableTeamFilter>,
+        pub team: Option<Box<NullableTeamFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub parent: Option<IssueLabelFilter>,
+        pub parent: Option<Box<IssueLabelFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<IssueLabelCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<IssueLabelCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub some: Option<IssueLabelFilter>,
+        pub some: Option<Box<IssueLabelFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub every: Option<IssueLabelFilter>,
+        pub every: Option<Box<IssueLabelFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct IssueLabelFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -796,7 +1048,7 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub creator: Option<Box<NullableUserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub team: Option<NullableTeamFilter>,
+        pub team: Option<Box<NullableTeamFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub parent: Option<Box<IssueLabelFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -805,7 +1057,7 @@ pub mod issue_list {
         pub or: Option<Vec<IssueLabelFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableTeamFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -829,7 +1081,7 @@ pub mod issue_list {
         pub or: Option<Vec<NullableTeamFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct SourceMetadataComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<String>,
@@ -845,7 +1097,7 @@ pub mod issue_list {
         pub sub_type: Option<SubTypeComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct SubTypeComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<String>,
@@ -859,409 +1111,699 @@ pub mod issue_list {
         pub null: Option<bool>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableTimelessDateComparator {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<TimelessDateOrDuration>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub neq: Option<TimelessDateOrDuration>,
-        #[cynic(rename = "in")]
+        #[cynic(rename = "in", skip_serializing_if = "Option::is_none")]
         pub in_: Option<Vec<TimelessDateOrDuration>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nin: Option<Vec<TimelessDateOrDuration>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub null: Option<bool>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lt: Option<TimelessDateOrDuration>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lte: Option<TimelessDateOrDuration>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub gt: Option<TimelessDateOrDuration>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub gte: Option<TimelessDateOrDuration>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct EstimateSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct EstimateComparator {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<f64>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub neq: Option<f64>,
-        #[cynic(rename = "in")]
+        #[cynic(rename = "in", skip_serializing_if = "Option::is_none")]
         pub in_: Option<Vec<f64>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nin: Option<Vec<f64>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub null: Option<bool>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lt: Option<f64>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lte: Option<f64>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub gt: Option<f64>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub gte: Option<f64>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<NullableNumberComparator>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<NullableNumberComparator>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct DueDateSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CycleSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub current_cycle_first: Option<bool>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CreatedAtSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CompletedAtSort {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub order: Option<PaginationSortOrder>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CommentCollectionFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub body: Option<StringComparator>,
-        pub user: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub user: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issue: Option<Box<NullableIssueFilter>>,
-        pub project_update: Option<ProjectUpdateFilter>,
-        pub parent: Option<NullableCommentFilter>,
-        pub document_content: Option<DocumentContentFilter>,
-        pub reactions: Option<ReactionCollectionFilter>,
-        pub needs: Option<CustomerNeedCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_update: Option<Box<ProjectUpdateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent: Option<Box<NullableCommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub document_content: Option<Box<DocumentContentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub reactions: Option<Box<ReactionCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<CommentCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<CommentCollectionFilter>>,
-        pub some: Option<CommentFilter>,
-        pub every: Option<CommentFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub some: Option<Box<CommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub every: Option<Box<CommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CommentFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub body: Option<StringComparator>,
-        pub user: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub user: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issue: Option<Box<NullableIssueFilter>>,
-        pub project_update: Option<ProjectUpdateFilter>,
-        pub parent: Option<NullableCommentFilter>,
-        pub document_content: Option<DocumentContentFilter>,
-        pub reactions: Option<ReactionCollectionFilter>,
-        pub needs: Option<CustomerNeedCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_update: Option<Box<ProjectUpdateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent: Option<Box<NullableCommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub document_content: Option<Box<DocumentContentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub reactions: Option<Box<ReactionCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<CommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<CommentFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectUpdateFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
-        pub user: Option<UserFilter>,
-        pub project: Option<ProjectFilter>,
-        pub reactions: Option<ReactionCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub user: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project: Option<Box<ProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub reactions: Option<Box<ReactionCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<ProjectUpdateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ProjectUpdateFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub name: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub slug_id: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub state: Option<StringComparator>,
-        pub status: Option<ProjectStatusFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub status: Option<Box<ProjectStatusFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub priority: Option<NullableNumberComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub searchable_content: Option<ContentComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub completed_at: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub start_date: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub target_date: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub health: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_related_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_depended_on_by_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_depends_on_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_blocked_by_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_blocking_relations: Option<RelationExistsComparator>,
-        pub project_updates: Option<ProjectUpdatesCollectionFilter>,
-        pub creator: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_updates: Option<Box<ProjectUpdatesCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub creator: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lead: Option<Box<NullableUserFilter>>,
-        pub members: Option<UserCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub members: Option<Box<UserCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issues: Option<Box<IssueCollectionFilter>>,
-        pub roadmaps: Option<RoadmapCollectionFilter>,
-        pub initiatives: Option<InitiativeCollectionFilter>,
-        pub project_milestones: Option<ProjectMilestoneCollectionFilter>,
-        pub completed_project_milestones: Option<ProjectMilestoneCollectionFilter>,
-        pub next_project_milestone: Option<ProjectMilestoneFilter>,
-        pub accessible_teams: Option<TeamCollectionFilter>,
-        pub last_applied_template: Option<NullableTemplateFilter>,
-        pub needs: Option<CustomerNeedCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub roadmaps: Option<Box<RoadmapCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub initiatives: Option<Box<InitiativeCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub completed_project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub next_project_milestone: Option<Box<ProjectMilestoneFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub accessible_teams: Option<Box<TeamCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<ProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ProjectFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectStatusFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub name: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub description: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub position: Option<NumberComparator>,
-        #[cynic(rename = "type")]
+        #[cynic(rename = "type", skip_serializing_if = "Option::is_none")]
         pub type_: Option<StringComparator>,
-        pub projects: Option<ProjectCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub projects: Option<Box<ProjectCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<ProjectStatusFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ProjectStatusFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectCollectionFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub name: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub slug_id: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub state: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub status: Option<Box<ProjectStatusFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub priority: Option<NullableNumberComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub searchable_content: Option<ContentComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub completed_at: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub start_date: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub target_date: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub health: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_related_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_depended_on_by_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_depends_on_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_blocked_by_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_blocking_relations: Option<RelationExistsComparator>,
-        pub project_updates: Option<ProjectUpdatesCollectionFilter>,
-        pub creator: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_updates: Option<Box<ProjectUpdatesCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub creator: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lead: Option<Box<NullableUserFilter>>,
-        pub members: Option<UserCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub members: Option<Box<UserCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issues: Option<Box<IssueCollectionFilter>>,
-        pub roadmaps: Option<RoadmapCollectionFilter>,
-        pub initiatives: Option<InitiativeCollectionFilter>,
-        pub project_milestones: Option<ProjectMilestoneCollectionFilter>,
-        pub completed_project_milestones: Option<ProjectMilestoneCollectionFilter>,
-        pub next_project_milestone: Option<ProjectMilestoneFilter>,
-        pub accessible_teams: Option<TeamCollectionFilter>,
-        pub last_applied_template: Option<NullableTemplateFilter>,
-        pub needs: Option<CustomerNeedCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub roadmaps: Option<Box<RoadmapCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub initiatives: Option<Box<InitiativeCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub completed_project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub next_project_milestone: Option<Box<ProjectMilestoneFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub accessible_teams: Option<Box<TeamCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<ProjectCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ProjectCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub some: Option<Box<ProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub every: Option<Box<ProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CustomerNeedCollectionFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub priority: Option<NumberComparator>,
-        pub project: Option<NullableProjectFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project: Option<Box<NullableProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issue: Option<Box<NullableIssueFilter>>,
-        pub comment: Option<NullableCommentFilter>,
-        pub customer: Option<CustomerFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub comment: Option<Box<NullableCommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub customer: Option<Box<CustomerFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<CustomerNeedCollectionFilter>>,
-        pub some: Option<CustomerNeedFilter>,
-        pub every: Option<CustomerNeedFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub some: Option<Box<CustomerNeedFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub every: Option<Box<CustomerNeedFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CustomerNeedFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub priority: Option<NumberComparator>,
-        pub project: Option<NullableProjectFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project: Option<Box<NullableProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issue: Option<Box<NullableIssueFilter>>,
-        pub comment: Option<NullableCommentFilter>,
-        pub customer: Option<CustomerFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub comment: Option<Box<NullableCommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub customer: Option<Box<CustomerFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<CustomerNeedFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<CustomerNeedFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct CustomerFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub name: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub slack_channel_id: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub domains: Option<StringArrayComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub external_ids: Option<StringArrayComparator>,
-        pub owner: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub owner: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<CustomerFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<CustomerFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct StringArrayComparator {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub every: Option<Vec<StringItemComparator>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub some: Option<Vec<StringItemComparator>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct StringItemComparator {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub neq: Option<String>,
-        #[cynic(rename = "in")]
+        #[cynic(rename = "in", skip_serializing_if = "Option::is_none")]
         pub in_: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub nin: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq_ignore_case: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub neq_ignore_case: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub starts_with: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub starts_with_ignore_case: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub not_starts_with: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub ends_with: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub not_ends_with: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub contains: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub contains_ignore_case: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub not_contains: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub not_contains_ignore_case: Option<String>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableCommentFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub body: Option<StringComparator>,
-        pub user: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub user: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issue: Option<Box<NullableIssueFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub project_update: Option<Box<ProjectUpdateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub parent: Option<Box<NullableCommentFilter>>,
-        pub document_content: Option<DocumentContentFilter>,
-        pub reactions: Option<ReactionCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub document_content: Option<Box<DocumentContentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub reactions: Option<Box<ReactionCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub null: Option<bool>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<NullableCommentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<NullableCommentFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ReactionCollectionFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub emoji: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub custom_emoji_id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<ReactionCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ReactionCollectionFilter>>,
-        pub some: Option<ReactionFilter>,
-        pub every: Option<ReactionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub some: Option<Box<ReactionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub every: Option<Box<ReactionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ReactionFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub emoji: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub custom_emoji_id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<ReactionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ReactionFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct DocumentContentFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub project: Option<Box<ProjectFilter>>,
-        pub document: Option<DocumentFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub document: Option<Box<DocumentFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct DocumentFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub title: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub slug_id: Option<StringComparator>,
-        pub creator: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub creator: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub project: Option<Box<ProjectFilter>>,
-        pub initiative: Option<InitiativeFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub initiative: Option<Box<InitiativeFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<DocumentFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<DocumentFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableProjectFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub name: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub slug_id: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub state: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub status: Option<Box<ProjectStatusFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub priority: Option<NullableNumberComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub searchable_content: Option<ContentComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub completed_at: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub start_date: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub target_date: Option<NullableDateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub health: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_related_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_depended_on_by_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_depends_on_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_blocked_by_relations: Option<RelationExistsComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub has_blocking_relations: Option<RelationExistsComparator>,
-        pub project_updates: Option<ProjectUpdatesCollectionFilter>,
-        pub creator: Option<UserFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_updates: Option<Box<ProjectUpdatesCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub creator: Option<Box<UserFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub lead: Option<Box<NullableUserFilter>>,
-        pub members: Option<UserCollectionFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub members: Option<Box<UserCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub issues: Option<Box<IssueCollectionFilter>>,
-        pub roadmaps: Option<RoadmapCollectionFilter>,
-        pub initiatives: Option<InitiativeCollectionFilter>,
-        pub project_milestones: Option<ProjectMilestoneCollectionFilter>,
-        pub completed_project_milestones: Option<ProjectMilestoneCollectionFilter>,
-        pub next_project_milestone: Option<ProjectMilestoneFilter>,
-        pub accessible_teams: Option<TeamCollectionFilter>,
-        pub last_applied_template: Option<NullableTemplateFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub roadmaps: Option<Box<RoadmapCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub initiatives: Option<Box<InitiativeCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub completed_project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub next_project_milestone: Option<Box<ProjectMilestoneFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub accessible_teams: Option<Box<TeamCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub null: Option<bool>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<NullableProjectFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<NullableProjectFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableTemplateFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub name: Option<StringComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub null: Option<bool>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<NullableTemplateFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<NullableTemplateFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct TeamCollectionFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub created_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub updated_at: Option<DateComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<TeamCollectionFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<TeamCollectionFilter>>,
-        pub some: Option<TeamFilter>,
-        pub every: Option<TeamFilter>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub some: Option<Box<TeamFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub every: Option<Box<TeamFilter>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug, Default)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct TeamFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1283,7 +1825,7 @@ pub mod issue_list {
         pub or: Option<Vec<TeamFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectMilestoneCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1300,14 +1842,14 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ProjectMilestoneCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub some: Option<ProjectMilestoneFilter>,
+        pub some: Option<Box<ProjectMilestoneFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub every: Option<ProjectMilestoneFilter>,
+        pub every: Option<Box<ProjectMilestoneFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectMilestoneFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1325,7 +1867,7 @@ pub mod issue_list {
         pub or: Option<Vec<ProjectMilestoneFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct InitiativeCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1340,20 +1882,20 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub status: Option<StringComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub creator: Option<UserFilter>,
+        pub creator: Option<Box<UserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<InitiativeCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<InitiativeCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub some: Option<InitiativeFilter>,
+        pub some: Option<Box<InitiativeFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub every: Option<InitiativeFilter>,
+        pub every: Option<Box<InitiativeFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct InitiativeFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1368,14 +1910,14 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub status: Option<StringComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub creator: Option<UserFilter>,
+        pub creator: Option<Box<UserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<InitiativeFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<InitiativeFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct RoadmapCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1388,20 +1930,20 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub slug_id: Option<StringComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub creator: Option<UserFilter>,
+        pub creator: Option<Box<UserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<RoadmapCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<RoadmapCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub some: Option<RoadmapFilter>,
+        pub some: Option<Box<RoadmapFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub every: Option<RoadmapFilter>,
+        pub every: Option<Box<RoadmapFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct RoadmapFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1414,14 +1956,14 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub slug_id: Option<StringComparator>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub creator: Option<UserFilter>,
+        pub creator: Option<Box<UserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub and: Option<Vec<RoadmapFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<RoadmapFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct UserCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1448,14 +1990,14 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<UserCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub some: Option<UserFilter>,
+        pub some: Option<Box<UserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub every: Option<UserFilter>,
+        pub every: Option<Box<UserFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectUpdatesCollectionFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1470,14 +2012,14 @@ pub mod issue_list {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub or: Option<Vec<ProjectUpdatesCollectionFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub some: Option<ProjectUpdatesFilter>,
+        pub some: Option<Box<ProjectUpdatesFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
-        pub every: Option<ProjectUpdatesFilter>,
+        pub every: Option<Box<ProjectUpdatesFilter>>,
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub length: Option<NumberComparator>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ProjectUpdatesFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1493,7 +2035,7 @@ pub mod issue_list {
         pub or: Option<Vec<ProjectUpdatesFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct RelationExistsComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<bool>,
@@ -1501,7 +2043,7 @@ pub mod issue_list {
         pub neq: Option<bool>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableDateComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<DateTimeOrDuration>,
@@ -1523,7 +2065,7 @@ pub mod issue_list {
         pub gte: Option<DateTimeOrDuration>,
     }
 
-    #[derive(cynic::InputObject, Debug, Default)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct ContentComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub contains: Option<String>,
@@ -1531,7 +2073,7 @@ pub mod issue_list {
         pub not_contains: Option<String>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableNumberComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<f64>,
@@ -1553,7 +2095,7 @@ pub mod issue_list {
         pub gte: Option<f64>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct UserFilter {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub id: Option<Idcomparator>,
@@ -1581,7 +2123,26 @@ pub mod issue_list {
         pub or: Option<Vec<UserFilter>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    impl UserFilter {
+        pub fn username(name: String) -> Self {
+            Self {
+                display_name: Some(StringComparator {
+                    eq: Some(name),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        }
+
+        pub fn me() -> Self {
+            Self {
+                is_me: Some(BooleanComparator::is_true()),
+                ..Default::default()
+            }
+        }
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct BooleanComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<bool>,
@@ -1605,7 +2166,7 @@ pub mod issue_list {
         }
     }
 
-    // #[derive(cynic::InputObject, Debug)]
+    // #[derive(cynic::InputObject, Clone, Debug, Default)]
     // pub struct AttachmentCollectionFilter {
     //     pub id: Option<Idcomparator>,
     //     pub created_at: Option<DateComparator>,
@@ -1622,7 +2183,7 @@ pub mod issue_list {
     //     pub length: Option<NumberComparator>,
     // }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NumberComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<f64>,
@@ -1642,7 +2203,7 @@ pub mod issue_list {
         pub gte: Option<f64>,
     }
 
-    // #[derive(cynic::InputObject, Debug)]
+    // #[derive(cynic::InputObject, Clone, Debug, Default)]
     // pub struct AttachmentFilter {
     //     pub id: Option<Idcomparator>,
     //     pub created_at: Option<DateComparator>,
@@ -1656,7 +2217,7 @@ pub mod issue_list {
     //     pub or: Option<Vec<AttachmentFilter>>,
     // }
 
-    // #[derive(cynic::InputObject, Debug)]
+    // #[derive(cynic::InputObject, Clone, Debug, Default)]
     // pub struct SourceTypeComparator {
     //     pub eq: Option<String>,
     //     pub neq: Option<String>,
@@ -1676,7 +2237,7 @@ pub mod issue_list {
     //     pub not_contains_ignore_case: Option<String>,
     // }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct NullableStringComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<String>,
@@ -1712,7 +2273,7 @@ pub mod issue_list {
         pub not_contains_ignore_case: Option<String>,
     }
 
-    #[derive(cynic::InputObject, Debug, Default)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct StringComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<String>,
@@ -1753,9 +2314,16 @@ pub mod issue_list {
                 ..Default::default()
             }
         }
+
+        pub fn eq_ignore_case(value: String) -> Self {
+            Self {
+                eq_ignore_case: Some(value),
+                ..Default::default()
+            }
+        }
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct DateComparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub eq: Option<DateTimeOrDuration>,
@@ -1775,7 +2343,7 @@ pub mod issue_list {
         pub gte: Option<DateTimeOrDuration>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     #[cynic(graphql_type = "IDComparator")]
     pub struct Idcomparator {
         #[cynic(skip_serializing_if = "Option::is_none")]
@@ -1788,7 +2356,7 @@ pub mod issue_list {
         pub nin: Option<Vec<cynic::Id>>,
     }
 
-    #[derive(cynic::InputObject, Debug)]
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
     pub struct AssigneeSort {
         #[cynic(skip_serializing_if = "Option::is_none")]
         pub nulls: Option<PaginationNulls>,
@@ -1821,3 +2389,1808 @@ pub mod issue_get {
         pub issue: super::issue_list::Issue,
     }
 }
+
+/// Small queries used to resolve human readable names (team keys, user names,
+/// label names, ...) to IDs.
+pub mod lookup {
+    use super::{
+        issue_list::{
+            BooleanComparator, IssueLabelFilter, NumberComparator, ProjectFilter, TeamFilter,
+            UserFilter, WorkflowStateFilter,
+        },
+        schema, DateTime, User,
+    };
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "Team")]
+    pub struct TeamRef {
+        pub id: cynic::Id,
+        pub key: String,
+        pub name: String,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct TeamsVariables {
+        pub filter: Option<TeamFilter>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "TeamsVariables")]
+    pub struct Teams {
+        #[arguments(filter: $filter, first: 250)]
+        pub teams: TeamRefConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "TeamConnection")]
+    pub struct TeamRefConnection {
+        pub nodes: Vec<TeamRef>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct UsersVariables {
+        pub filter: Option<UserFilter>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "UsersVariables")]
+    pub struct Users {
+        #[arguments(filter: $filter, first: 50)]
+        pub users: UserConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct UserConnection {
+        pub nodes: Vec<User>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "WorkflowState")]
+    pub struct WorkflowStateRef {
+        pub id: cynic::Id,
+        pub name: String,
+        #[cynic(rename = "type")]
+        #[serde(rename = "type")]
+        pub type_: String,
+        pub color: String,
+        pub position: f64,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct WorkflowStatesVariables {
+        pub filter: Option<WorkflowStateFilter>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "WorkflowStatesVariables")]
+    pub struct WorkflowStates {
+        #[arguments(filter: $filter, first: 250)]
+        pub workflow_states: WorkflowStateRefConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "WorkflowStateConnection")]
+    pub struct WorkflowStateRefConnection {
+        pub nodes: Vec<WorkflowStateRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "IssueLabel")]
+    pub struct IssueLabelRef {
+        pub id: cynic::Id,
+        pub name: String,
+        pub color: String,
+        pub is_group: bool,
+        pub team: Option<TeamRef>,
+        pub parent: Option<IssueLabelParentRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "IssueLabel")]
+    pub struct IssueLabelParentRef {
+        pub id: cynic::Id,
+        pub name: String,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueLabelsVariables {
+        pub filter: Option<IssueLabelFilter>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueLabelsVariables")]
+    pub struct IssueLabels {
+        #[arguments(filter: $filter, first: 250)]
+        pub issue_labels: IssueLabelRefConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "IssueLabelConnection")]
+    pub struct IssueLabelRefConnection {
+        pub nodes: Vec<IssueLabelRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct IssueRef {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "Project")]
+    pub struct ProjectRef {
+        pub id: cynic::Id,
+        pub name: String,
+        pub slug_id: String,
+        pub url: String,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct ProjectsVariables {
+        pub filter: Option<ProjectFilter>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "ProjectsVariables")]
+    pub struct Projects {
+        #[arguments(filter: $filter, first: 50)]
+        pub projects: ProjectRefConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "ProjectConnection")]
+    pub struct ProjectRefConnection {
+        pub nodes: Vec<ProjectRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "Cycle")]
+    pub struct CycleRef {
+        pub id: cynic::Id,
+        pub number: f64,
+        pub name: Option<String>,
+        pub starts_at: DateTime,
+        pub ends_at: DateTime,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CyclesVariables {
+        pub filter: Option<CycleFilter>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "CyclesVariables")]
+    pub struct Cycles {
+        #[arguments(filter: $filter, first: 50)]
+        pub cycles: CycleRefConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "CycleConnection")]
+    pub struct CycleRefConnection {
+        pub nodes: Vec<CycleRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "ProjectStatus")]
+    pub struct ProjectStatusRef {
+        pub id: cynic::Id,
+        pub name: String,
+        pub position: f64,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query")]
+    pub struct ProjectStatuses {
+        pub organization: OrganizationProjectStatuses,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Organization")]
+    pub struct OrganizationProjectStatuses {
+        pub project_statuses: Vec<ProjectStatusRef>,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct CycleFilter {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub number: Option<NumberComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub is_active: Option<BooleanComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub is_next: Option<BooleanComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub is_previous: Option<BooleanComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub is_future: Option<BooleanComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub is_past: Option<BooleanComparator>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub team: Option<Box<TeamFilter>>,
+    }
+}
+
+pub mod issue_create {
+    use super::{issue_list::Issue, schema};
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueCreateVariables {
+        pub input: IssueCreateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueCreateVariables")]
+    pub struct IssueCreate {
+        #[arguments(input: $input)]
+        pub issue_create: IssuePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssuePayload {
+        pub success: bool,
+        pub issue: Option<Issue>,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct IssueCreateInput {
+        pub team_id: String,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub title: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub assignee_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub priority: Option<i32>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub estimate: Option<i32>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub label_ids: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub cycle_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub state_id: Option<String>,
+    }
+}
+
+pub mod issue_update {
+    use super::{
+        issue_list::{Issue, TimelessDate},
+        schema, Nullable,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueUpdateVariables {
+        pub id: String,
+        pub input: IssueUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueUpdateVariables")]
+    pub struct IssueUpdate {
+        #[arguments(id: $id, input: $input)]
+        pub issue_update: IssuePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssuePayload {
+        pub success: bool,
+        pub issue: Option<Issue>,
+    }
+
+    /// Fields that are `None` are left unchanged.
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct IssueUpdateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub title: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub assignee_id: Option<Nullable<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<Nullable<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub priority: Option<i32>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub estimate: Option<Nullable<i32>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub label_ids: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub team_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub cycle_id: Option<Nullable<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_id: Option<Nullable<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub state_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub due_date: Option<Nullable<TimelessDate>>,
+    }
+}
+
+pub mod issue_batch_update {
+    use super::{issue_list::Issue, issue_update::IssueUpdateInput, schema, Uuid};
+
+    /// Maximum number of issues that can be updated with a single mutation.
+    pub const MAX_BATCH_SIZE: usize = 50;
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueBatchUpdateVariables {
+        pub ids: Vec<Uuid>,
+        pub input: IssueUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueBatchUpdateVariables")]
+    pub struct IssueBatchUpdate {
+        #[arguments(ids: $ids, input: $input)]
+        pub issue_batch_update: IssueBatchPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssueBatchPayload {
+        pub success: bool,
+        pub issues: Vec<Issue>,
+    }
+}
+
+pub mod comments {
+    use super::{schema, DateTime, PageInfo, User};
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct Comment {
+        pub id: cynic::Id,
+        pub body: String,
+        pub created_at: DateTime,
+        pub updated_at: DateTime,
+        pub edited_at: Option<DateTime>,
+        pub resolved_at: Option<DateTime>,
+        pub resolving_user: Option<User>,
+        pub user: Option<User>,
+        pub parent: Option<CommentRef>,
+        pub url: String,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "Comment")]
+    pub struct CommentRef {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryVariables, Clone, Debug)]
+    pub struct IssueCommentsVariables {
+        pub id: String,
+        pub first: i32,
+        pub after: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueCommentsVariables")]
+    pub struct IssueComments {
+        #[arguments(id: $id)]
+        pub issue: IssueWithComments,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Issue", variables = "IssueCommentsVariables")]
+    pub struct IssueWithComments {
+        #[arguments(first: $first, after: $after)]
+        pub comments: CommentConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct CommentConnection {
+        pub page_info: PageInfo,
+        pub nodes: Vec<Comment>,
+    }
+
+    impl crate::pagination::PaginatedQuery for IssueComments {
+        type Variables = IssueCommentsVariables;
+        type Node = Comment;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.issue.comments.page_info, self.issue.comments.nodes)
+        }
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CommentIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "CommentIdVariables")]
+    pub struct CommentGet {
+        #[arguments(id: $id)]
+        pub comment: Comment,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct CommentPayload {
+        pub success: bool,
+        pub comment: Comment,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct CommentCreateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub body: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub issue_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<String>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CommentCreateVariables {
+        pub input: CommentCreateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CommentCreateVariables")]
+    pub struct CommentCreate {
+        #[arguments(input: $input)]
+        pub comment_create: CommentPayload,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct CommentUpdateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub body: Option<String>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CommentUpdateVariables {
+        pub id: String,
+        pub input: CommentUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CommentUpdateVariables")]
+    pub struct CommentUpdate {
+        #[arguments(id: $id, input: $input)]
+        pub comment_update: CommentPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct DeletePayload {
+        pub success: bool,
+        pub entity_id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CommentIdVariables")]
+    pub struct CommentDelete {
+        #[arguments(id: $id)]
+        pub comment_delete: DeletePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CommentIdVariables")]
+    pub struct CommentResolve {
+        #[arguments(id: $id)]
+        pub comment_resolve: CommentPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CommentIdVariables")]
+    pub struct CommentUnresolve {
+        #[arguments(id: $id)]
+        pub comment_unresolve: CommentPayload,
+    }
+}
+
+pub mod issue_view {
+    use super::{
+        comments::Comment,
+        issue_list::{IssueLabelConnection, TimelessDate, WorkflowState},
+        lookup::{CycleRef, IssueRef, ProjectRef, TeamRef},
+        schema, DateTime, User,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueViewVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueViewVariables")]
+    pub struct IssueView {
+        #[arguments(id: $id)]
+        pub issue: IssueDetail,
+    }
+
+    /// All details of an issue, including related entities.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct IssueDetail {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub description: Option<String>,
+        pub url: String,
+        pub branch_name: String,
+        pub created_at: DateTime,
+        pub updated_at: DateTime,
+        pub started_at: Option<DateTime>,
+        pub completed_at: Option<DateTime>,
+        pub canceled_at: Option<DateTime>,
+        pub due_date: Option<TimelessDate>,
+        pub estimate: Option<f64>,
+        pub priority: f64,
+        pub priority_label: String,
+        pub state: WorkflowState,
+        pub team: TeamRef,
+        pub assignee: Option<User>,
+        pub creator: Option<User>,
+        pub labels: IssueLabelConnection,
+        pub project: Option<ProjectRef>,
+        pub cycle: Option<CycleRef>,
+        pub project_milestone: Option<ProjectMilestoneRef>,
+        pub parent: Option<IssueRef>,
+        #[arguments(first: 100)]
+        pub children: IssueSummaryConnection,
+        #[arguments(first: 100)]
+        pub relations: IssueRelationConnection,
+        #[arguments(first: 100)]
+        pub inverse_relations: InverseIssueRelationConnection,
+        #[arguments(first: 50)]
+        pub attachments: AttachmentConnection,
+        #[arguments(first: 50)]
+        pub subscribers: UserConnection,
+        /// The most recent comments.
+        #[arguments(last: 100)]
+        pub comments: CommentList,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "CommentConnection")]
+    pub struct CommentList {
+        pub nodes: Vec<Comment>,
+        pub page_info: CommentPageInfo,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "PageInfo")]
+    pub struct CommentPageInfo {
+        /// Whether there are older comments than the loaded ones.
+        pub has_previous_page: bool,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "ProjectMilestone")]
+    pub struct ProjectMilestoneRef {
+        pub id: cynic::Id,
+        pub name: String,
+        pub target_date: Option<TimelessDate>,
+    }
+
+    /// Short issue info shown in lists of related issues.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct IssueSummary {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub state: WorkflowState,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueConnection")]
+    pub struct IssueSummaryConnection {
+        pub nodes: Vec<IssueSummary>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueRelation {
+        pub id: cynic::Id,
+        #[cynic(rename = "type")]
+        #[serde(rename = "type")]
+        pub type_: String,
+        pub related_issue: IssueSummary,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueRelationConnection {
+        pub nodes: Vec<IssueRelation>,
+    }
+
+    /// A relation pointing to this issue from another issue.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueRelation")]
+    pub struct InverseIssueRelation {
+        pub id: cynic::Id,
+        #[cynic(rename = "type")]
+        #[serde(rename = "type")]
+        pub type_: String,
+        pub issue: IssueSummary,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueRelationConnection")]
+    pub struct InverseIssueRelationConnection {
+        pub nodes: Vec<InverseIssueRelation>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct Attachment {
+        pub id: cynic::Id,
+        pub title: String,
+        pub subtitle: Option<String>,
+        pub url: String,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct AttachmentConnection {
+        pub nodes: Vec<Attachment>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct UserConnection {
+        pub nodes: Vec<User>,
+    }
+}
+
+pub mod projects {
+    use super::{
+        issue_list::TimelessDate,
+        issue_view::ProjectMilestoneRef,
+        lookup::{ProjectRef, TeamRef},
+        projects_list::ProjectStatus,
+        schema, DateTime, Nullable, PageInfo, User,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct ProjectIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "ProjectIdVariables")]
+    pub struct ProjectGet {
+        #[arguments(id: $id)]
+        pub project: ProjectDetail,
+    }
+
+    /// All details of a project, including members, milestones and issues.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Project")]
+    pub struct ProjectDetail {
+        pub id: cynic::Id,
+        pub name: String,
+        pub description: String,
+        pub content: Option<String>,
+        pub slug_id: String,
+        pub url: String,
+        pub status: ProjectStatus,
+        pub health: Option<ProjectUpdateHealthType>,
+        pub priority: i32,
+        pub progress: f64,
+        pub scope: f64,
+        pub creator: Option<User>,
+        pub lead: Option<User>,
+        pub start_date: Option<TimelessDate>,
+        pub target_date: Option<TimelessDate>,
+        pub created_at: DateTime,
+        pub updated_at: DateTime,
+        pub started_at: Option<DateTime>,
+        pub completed_at: Option<DateTime>,
+        pub canceled_at: Option<DateTime>,
+        pub archived_at: Option<DateTime>,
+        #[arguments(first: 50)]
+        pub teams: TeamConnection,
+        #[arguments(first: 100)]
+        pub members: UserConnection,
+        #[arguments(first: 50)]
+        pub project_milestones: ProjectMilestoneConnection,
+        #[arguments(first: 10)]
+        pub project_updates: ProjectUpdateConnection,
+        #[arguments(first: 250)]
+        pub issues: ProjectIssueConnection,
+    }
+
+    #[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ProjectUpdateHealthType {
+        #[cynic(rename = "onTrack")]
+        OnTrack,
+        #[cynic(rename = "atRisk")]
+        AtRisk,
+        #[cynic(rename = "offTrack")]
+        OffTrack,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct TeamConnection {
+        pub nodes: Vec<TeamRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct UserConnection {
+        pub nodes: Vec<User>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct ProjectMilestoneConnection {
+        pub nodes: Vec<ProjectMilestoneRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct ProjectUpdate {
+        pub id: cynic::Id,
+        pub body: String,
+        pub health: ProjectUpdateHealthType,
+        pub created_at: DateTime,
+        pub user: User,
+        pub url: String,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct ProjectUpdateConnection {
+        pub nodes: Vec<ProjectUpdate>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueConnection")]
+    pub struct ProjectIssueConnection {
+        #[serde(skip)]
+        pub page_info: PageInfo,
+        pub nodes: Vec<ProjectIssue>,
+    }
+
+    /// Minimal issue info used to count the issues of a project by state.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct ProjectIssue {
+        pub id: cynic::Id,
+        pub state: ProjectIssueState,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "WorkflowState")]
+    pub struct ProjectIssueState {
+        pub name: String,
+        #[cynic(rename = "type")]
+        #[serde(rename = "type")]
+        pub type_: String,
+        pub position: f64,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectPayload {
+        pub success: bool,
+        pub project: Option<ProjectRef>,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct ProjectCreateInput {
+        pub name: String,
+        pub team_ids: Vec<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub lead_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub member_ids: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub status_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub priority: Option<i32>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub start_date: Option<TimelessDate>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub target_date: Option<TimelessDate>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct ProjectCreateVariables {
+        pub input: ProjectCreateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectCreateVariables")]
+    pub struct ProjectCreate {
+        #[arguments(input: $input)]
+        pub project_create: ProjectPayload,
+    }
+
+    /// Fields that are `None` are left unchanged.
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct ProjectUpdateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub lead_id: Option<Nullable<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub member_ids: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub team_ids: Option<Vec<String>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub status_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub priority: Option<i32>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub start_date: Option<Nullable<TimelessDate>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub target_date: Option<Nullable<TimelessDate>>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct ProjectUpdateVariables {
+        pub id: String,
+        pub input: ProjectUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectUpdateVariables")]
+    pub struct ProjectUpdateMutation {
+        #[arguments(id: $id, input: $input)]
+        pub project_update: ProjectPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectArchivePayload {
+        pub success: bool,
+        pub entity: Option<ProjectRef>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectIdVariables")]
+    pub struct ProjectArchive {
+        #[arguments(id: $id)]
+        pub project_archive: ProjectArchivePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectIdVariables")]
+    pub struct ProjectUnarchive {
+        #[arguments(id: $id)]
+        pub project_unarchive: ProjectArchivePayload,
+    }
+}
+
+pub mod teams {
+    use super::{
+        issue_list::IssueLabel,
+        lookup::{CycleRef, WorkflowStateRef},
+        schema, PageInfo,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct TeamIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "TeamIdVariables")]
+    pub struct TeamGet {
+        #[arguments(id: $id)]
+        pub team: TeamDetail,
+    }
+
+    /// All details of a team, including members, workflow states and
+    /// settings.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Team")]
+    pub struct TeamDetail {
+        pub id: cynic::Id,
+        pub key: String,
+        pub name: String,
+        pub description: Option<String>,
+        pub private: bool,
+        pub timezone: String,
+        pub cycles_enabled: bool,
+        pub cycle_start_day: f64,
+        pub cycle_duration: f64,
+        pub cycle_cooldown_time: f64,
+        pub upcoming_cycle_count: f64,
+        pub active_cycle: Option<CycleRef>,
+        pub triage_enabled: bool,
+        pub require_priority_to_leave_triage: bool,
+        pub triage_issue_state: Option<WorkflowStateRef>,
+        pub default_issue_state: Option<WorkflowStateRef>,
+        pub issue_estimation_type: String,
+        pub issue_estimation_allow_zero: bool,
+        pub issue_estimation_extended: bool,
+        pub default_issue_estimate: f64,
+        #[arguments(first: 250)]
+        pub members: TeamMemberConnection,
+        #[arguments(first: 100)]
+        pub states: WorkflowStateConnection,
+        #[arguments(first: 250)]
+        pub labels: IssueLabelConnection,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "User")]
+    pub struct TeamMember {
+        pub id: cynic::Id,
+        pub name: String,
+        pub display_name: String,
+        pub email: String,
+        pub active: bool,
+        pub admin: bool,
+        pub is_me: bool,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "UserConnection")]
+    pub struct TeamMemberConnection {
+        #[serde(skip)]
+        pub page_info: PageInfo,
+        pub nodes: Vec<TeamMember>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct WorkflowStateConnection {
+        pub nodes: Vec<WorkflowStateRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueLabelConnection {
+        pub nodes: Vec<IssueLabel>,
+    }
+
+    #[derive(cynic::QueryVariables, Clone, Debug)]
+    pub struct TeamMembersVariables {
+        pub id: String,
+        pub first: i32,
+        pub after: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "TeamMembersVariables")]
+    pub struct TeamMembers {
+        #[arguments(id: $id)]
+        pub team: TeamWithMembers,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Team", variables = "TeamMembersVariables")]
+    pub struct TeamWithMembers {
+        #[arguments(first: $first, after: $after)]
+        pub members: TeamMemberConnection,
+    }
+
+    impl crate::pagination::PaginatedQuery for TeamMembers {
+        type Variables = TeamMembersVariables;
+        type Node = TeamMember;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.team.members.page_info, self.team.members.nodes)
+        }
+    }
+}
+
+pub mod cycles {
+    use super::{
+        lookup::{CycleFilter, TeamRef, WorkflowStateRef},
+        schema, DateTime, PageInfo, User,
+    };
+
+    #[derive(cynic::QueryVariables, Clone, Debug)]
+    pub struct CycleListVariables {
+        pub filter: Option<CycleFilter>,
+        pub first: i32,
+        pub after: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "CycleListVariables")]
+    pub struct CycleList {
+        #[arguments(filter: $filter, first: $first, after: $after)]
+        pub cycles: CycleConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct CycleConnection {
+        pub page_info: PageInfo,
+        pub nodes: Vec<Cycle>,
+    }
+
+    impl crate::pagination::PaginatedQuery for CycleList {
+        type Variables = CycleListVariables;
+        type Node = Cycle;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.cycles.page_info, self.cycles.nodes)
+        }
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct Cycle {
+        pub id: cynic::Id,
+        pub number: f64,
+        pub name: Option<String>,
+        pub description: Option<String>,
+        pub starts_at: DateTime,
+        pub ends_at: DateTime,
+        pub completed_at: Option<DateTime>,
+        pub progress: f64,
+        pub team: TeamRef,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CycleIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "CycleIdVariables")]
+    pub struct CycleGet {
+        #[arguments(id: $id)]
+        pub cycle: CycleDetail,
+    }
+
+    /// A cycle with its issues and the daily scope history.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Cycle")]
+    pub struct CycleDetail {
+        pub id: cynic::Id,
+        pub number: f64,
+        pub name: Option<String>,
+        pub description: Option<String>,
+        pub starts_at: DateTime,
+        pub ends_at: DateTime,
+        pub completed_at: Option<DateTime>,
+        pub progress: f64,
+        pub team: TeamRef,
+        pub issue_count_history: Vec<f64>,
+        pub completed_issue_count_history: Vec<f64>,
+        pub scope_history: Vec<f64>,
+        pub completed_scope_history: Vec<f64>,
+        pub in_progress_scope_history: Vec<f64>,
+        #[arguments(first: 250)]
+        pub issues: CycleIssueConnection,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct CycleIssue {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub estimate: Option<f64>,
+        pub state: WorkflowStateRef,
+        pub assignee: Option<User>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueConnection")]
+    pub struct CycleIssueConnection {
+        pub nodes: Vec<CycleIssue>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct CyclePayload {
+        pub success: bool,
+        pub cycle: Option<Cycle>,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct CycleCreateInput {
+        pub team_id: String,
+        pub starts_at: DateTime,
+        pub ends_at: DateTime,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CycleCreateVariables {
+        pub input: CycleCreateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CycleCreateVariables")]
+    pub struct CycleCreate {
+        #[arguments(input: $input)]
+        pub cycle_create: CyclePayload,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct CycleUpdateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub starts_at: Option<DateTime>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub ends_at: Option<DateTime>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct CycleUpdateVariables {
+        pub id: String,
+        pub input: CycleUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CycleUpdateVariables")]
+    pub struct CycleUpdateMutation {
+        #[arguments(id: $id, input: $input)]
+        pub cycle_update: CyclePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "CycleIdVariables")]
+    pub struct CycleArchive {
+        #[arguments(id: $id)]
+        pub cycle_archive: CycleArchivePayload,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct CycleArchivePayload {
+        pub success: bool,
+    }
+}
+
+pub mod labels {
+    use super::{
+        comments::DeletePayload,
+        issue_list::IssueLabelFilter,
+        lookup::{IssueLabelParentRef, TeamRef},
+        schema, Nullable, PageInfo,
+    };
+
+    #[derive(cynic::QueryVariables, Clone, Debug)]
+    pub struct LabelListVariables {
+        pub filter: Option<IssueLabelFilter>,
+        pub first: i32,
+        pub after: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "LabelListVariables")]
+    pub struct LabelList {
+        #[arguments(filter: $filter, first: $first, after: $after)]
+        pub issue_labels: LabelConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "IssueLabelConnection")]
+    pub struct LabelConnection {
+        pub page_info: PageInfo,
+        pub nodes: Vec<Label>,
+    }
+
+    impl crate::pagination::PaginatedQuery for LabelList {
+        type Variables = LabelListVariables;
+        type Node = Label;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.issue_labels.page_info, self.issue_labels.nodes)
+        }
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueLabel")]
+    pub struct Label {
+        pub id: cynic::Id,
+        pub name: String,
+        pub description: Option<String>,
+        pub color: String,
+        pub is_group: bool,
+        pub team: Option<TeamRef>,
+        pub parent: Option<IssueLabelParentRef>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueLabelPayload {
+        pub success: bool,
+        pub issue_label: Label,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct IssueLabelCreateInput {
+        pub name: String,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub color: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub team_id: Option<String>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueLabelCreateVariables {
+        pub input: IssueLabelCreateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueLabelCreateVariables")]
+    pub struct IssueLabelCreate {
+        #[arguments(input: $input)]
+        pub issue_label_create: IssueLabelPayload,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct IssueLabelUpdateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub color: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<Nullable<String>>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueLabelUpdateVariables {
+        pub id: String,
+        pub input: IssueLabelUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueLabelUpdateVariables")]
+    pub struct IssueLabelUpdate {
+        #[arguments(id: $id, input: $input)]
+        pub issue_label_update: IssueLabelPayload,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueLabelIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueLabelIdVariables")]
+    pub struct IssueLabelDelete {
+        #[arguments(id: $id)]
+        pub issue_label_delete: DeletePayload,
+    }
+}
+
+pub mod relations {
+    use super::{comments::DeletePayload, lookup::WorkflowStateRef, schema};
+
+    #[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum IssueRelationType {
+        #[cynic(rename = "blocks")]
+        Blocks,
+        #[cynic(rename = "duplicate")]
+        Duplicate,
+        #[cynic(rename = "related")]
+        Related,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueRelationsVariables {
+        pub id: String,
+    }
+
+    /// An issue with all relations from and to it.
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueRelationsVariables")]
+    pub struct IssueRelationsGet {
+        #[arguments(id: $id)]
+        pub issue: IssueWithRelations,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct IssueWithRelations {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub url: String,
+        pub state: WorkflowStateRef,
+        #[arguments(first: 100)]
+        pub relations: RelationConnection,
+        #[arguments(first: 100)]
+        pub inverse_relations: RelationConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "IssueRelationConnection")]
+    pub struct RelationConnection {
+        pub nodes: Vec<Relation>,
+    }
+
+    /// A relation, with `issue` as the source and `related_issue` as the
+    /// target (eg: `issue` blocks `related_issue`).
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "IssueRelation")]
+    pub struct Relation {
+        pub id: cynic::Id,
+        #[cynic(rename = "type")]
+        #[serde(rename = "type")]
+        pub type_: String,
+        pub issue: RelatedIssue,
+        pub related_issue: RelatedIssue,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Clone, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct RelatedIssue {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub state: WorkflowStateRef,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct IssueRelationCreateInput {
+        #[cynic(rename = "type")]
+        pub type_: IssueRelationType,
+        pub issue_id: String,
+        pub related_issue_id: String,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueRelationCreateVariables {
+        pub input: IssueRelationCreateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueRelationCreateVariables")]
+    pub struct IssueRelationCreate {
+        #[arguments(input: $input)]
+        pub issue_relation_create: IssueRelationPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssueRelationPayload {
+        pub success: bool,
+        pub issue_relation: Relation,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueRelationIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueRelationIdVariables")]
+    pub struct IssueRelationDelete {
+        #[arguments(id: $id)]
+        pub issue_relation_delete: DeletePayload,
+    }
+}
+
+pub mod issue_tree {
+    use super::{
+        lookup::{IssueRef, WorkflowStateRef},
+        schema, User,
+    };
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueTreeVariables {
+        pub id: String,
+    }
+
+    /// An issue with its direct sub-issues.
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueTreeVariables")]
+    pub struct IssueTreeGet {
+        #[arguments(id: $id)]
+        pub issue: TreeIssue,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct TreeIssue {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub estimate: Option<f64>,
+        pub state: WorkflowStateRef,
+        pub assignee: Option<User>,
+        pub parent: Option<IssueRef>,
+        #[arguments(first: 250)]
+        pub children: TreeChildConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "IssueConnection")]
+    pub struct TreeChildConnection {
+        pub nodes: Vec<TreeChild>,
+    }
+
+    /// A sub-issue, with the first of its own sub-issues to tell whether it
+    /// has any.
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct TreeChild {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub estimate: Option<f64>,
+        pub state: WorkflowStateRef,
+        pub assignee: Option<User>,
+        #[arguments(first: 1)]
+        pub children: TreeChildIds,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "IssueConnection")]
+    pub struct TreeChildIds {
+        pub nodes: Vec<IssueRef>,
+    }
+}
+
+pub mod search {
+    use super::{
+        lookup::{ProjectRef, TeamRef, WorkflowStateRef},
+        projects_list::ProjectStatus,
+        schema, DateTime, JsonObject, PageInfo, User,
+    };
+
+    /// Variables shared by the issue, project and document searches.
+    #[derive(cynic::QueryVariables, Clone, Debug)]
+    pub struct SearchVariables {
+        pub term: String,
+        pub team_id: Option<String>,
+        pub include_comments: Option<bool>,
+        pub first: i32,
+        pub after: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "SearchVariables")]
+    pub struct IssueSearch {
+        #[arguments(term: $term, teamId: $team_id, includeComments: $include_comments, first: $first, after: $after)]
+        pub search_issues: IssueSearchPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssueSearchPayload {
+        pub page_info: PageInfo,
+        pub nodes: Vec<IssueSearchResult>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct IssueSearchResult {
+        pub id: cynic::Id,
+        pub identifier: String,
+        pub title: String,
+        pub description: Option<String>,
+        pub url: String,
+        pub state: WorkflowStateRef,
+        pub team: TeamRef,
+        pub assignee: Option<User>,
+        pub updated_at: DateTime,
+        /// Search metadata, eg: the matched context.
+        pub metadata: JsonObject,
+    }
+
+    impl crate::pagination::PaginatedQuery for IssueSearch {
+        type Variables = SearchVariables;
+        type Node = IssueSearchResult;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.search_issues.page_info, self.search_issues.nodes)
+        }
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "SearchVariables")]
+    pub struct ProjectSearch {
+        #[arguments(term: $term, teamId: $team_id, includeComments: $include_comments, first: $first, after: $after)]
+        pub search_projects: ProjectSearchPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectSearchPayload {
+        pub page_info: PageInfo,
+        pub nodes: Vec<ProjectSearchResult>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct ProjectSearchResult {
+        pub id: cynic::Id,
+        pub name: String,
+        pub slug_id: String,
+        pub description: String,
+        pub content: Option<String>,
+        pub url: String,
+        pub status: ProjectStatus,
+        pub lead: Option<User>,
+        pub updated_at: DateTime,
+        /// Search metadata, eg: the matched context.
+        pub metadata: JsonObject,
+    }
+
+    impl crate::pagination::PaginatedQuery for ProjectSearch {
+        type Variables = SearchVariables;
+        type Node = ProjectSearchResult;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.search_projects.page_info, self.search_projects.nodes)
+        }
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "SearchVariables")]
+    pub struct DocumentSearch {
+        #[arguments(term: $term, teamId: $team_id, includeComments: $include_comments, first: $first, after: $after)]
+        pub search_documents: DocumentSearchPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct DocumentSearchPayload {
+        pub page_info: PageInfo,
+        pub nodes: Vec<DocumentSearchResult>,
+    }
+
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    pub struct DocumentSearchResult {
+        pub id: cynic::Id,
+        pub title: String,
+        pub slug_id: String,
+        pub content: Option<String>,
+        pub url: String,
+        pub project: Option<ProjectRef>,
+        pub creator: User,
+        pub updated_at: DateTime,
+        /// Search metadata, eg: the matched context.
+        pub metadata: JsonObject,
+    }
+
+    impl crate::pagination::PaginatedQuery for DocumentSearch {
+        type Variables = SearchVariables;
+        type Node = DocumentSearchResult;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            (self.search_documents.page_info, self.search_documents.nodes)
+        }
+    }
+}
+
+pub mod notifications {
+    use super::{
+        lookup::{IssueRef, ProjectRef, TeamRef},
+        schema, DateTime, Nullable, PageInfo, User,
+    };
+
+    #[derive(cynic::QueryVariables, Clone, Debug)]
+    pub struct NotificationListVariables {
+        pub include_archived: Option<bool>,
+        pub first: i32,
+        pub after: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "NotificationListVariables")]
+    pub struct NotificationList {
+        #[arguments(includeArchived: $include_archived, first: $first, after: $after)]
+        pub notifications: NotificationConnection,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NotificationConnection {
+        pub page_info: PageInfo,
+        pub nodes: Vec<NotificationFields>,
+        /// The same nodes again, for the fields of the concrete notification
+        /// types.
+        #[cynic(rename = "nodes", alias)]
+        pub subjects: Vec<NotificationSubject>,
+    }
+
+    impl crate::pagination::PaginatedQuery for NotificationList {
+        type Variables = NotificationListVariables;
+        type Node = Notification;
+
+        fn set_page(vars: &mut Self::Variables, first: i32, after: Option<String>) {
+            vars.first = first;
+            vars.after = after;
+        }
+
+        fn into_page(self) -> (PageInfo, Vec<Self::Node>) {
+            let nodes = self
+                .notifications
+                .nodes
+                .into_iter()
+                .zip(self.notifications.subjects)
+                .map(|(fields, subject)| Notification::new(fields, subject))
+                .collect();
+            (self.notifications.page_info, nodes)
+        }
+    }
+
+    /// A notification, with the issue or project it is about.
+    #[derive(serde::Serialize, Debug)]
+    pub struct Notification {
+        #[serde(flatten)]
+        pub fields: NotificationFields,
+        pub issue: Option<IssueRef>,
+        pub team: Option<TeamRef>,
+        pub project: Option<ProjectRef>,
+    }
+
+    impl Notification {
+        fn new(fields: NotificationFields, subject: NotificationSubject) -> Self {
+            let (issue, team, project) = match subject {
+                NotificationSubject::IssueNotification(n) => (Some(n.issue), Some(n.team), None),
+                NotificationSubject::ProjectNotification(n) => (None, None, Some(n.project)),
+                NotificationSubject::Other => (None, None, None),
+            };
+            Self {
+                fields,
+                issue,
+                team,
+                project,
+            }
+        }
+    }
+
+    /// The fields shared by all types of notifications.
+    #[derive(cynic::QueryFragment, serde::Serialize, Debug)]
+    #[cynic(graphql_type = "Notification")]
+    pub struct NotificationFields {
+        pub id: cynic::Id,
+        /// Eg: issueAssignedToYou, issueMention, issueNewComment or
+        /// issueStatusChanged.
+        #[cynic(rename = "type")]
+        #[serde(rename = "type")]
+        pub kind: String,
+        pub title: String,
+        pub subtitle: String,
+        pub url: String,
+        pub actor: Option<User>,
+        pub created_at: DateTime,
+        pub read_at: Option<DateTime>,
+        pub snoozed_until_at: Option<DateTime>,
+        pub archived_at: Option<DateTime>,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    #[cynic(graphql_type = "Notification")]
+    pub enum NotificationSubject {
+        IssueNotification(IssueSubject),
+        ProjectNotification(ProjectSubject),
+        #[cynic(fallback)]
+        Other,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "IssueNotification")]
+    pub struct IssueSubject {
+        pub issue: IssueRef,
+        pub team: TeamRef,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "ProjectNotification")]
+    pub struct ProjectSubject {
+        pub project: ProjectRef,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct NotificationUpdateInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub read_at: Option<Nullable<DateTime>>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub snoozed_until_at: Option<Nullable<DateTime>>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct NotificationUpdateVariables {
+        pub id: String,
+        pub input: NotificationUpdateInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "NotificationUpdateVariables")]
+    pub struct NotificationUpdate {
+        #[arguments(id: $id, input: $input)]
+        pub notification_update: NotificationPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NotificationPayload {
+        pub success: bool,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct NotificationIdVariables {
+        pub id: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "NotificationIdVariables")]
+    pub struct NotificationArchive {
+        #[arguments(id: $id)]
+        pub notification_archive: NotificationArchivePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NotificationArchivePayload {
+        pub success: bool,
+    }
+
+    /// The notifications a batch action applies to: a notification and all
+    /// related notifications, or all notifications of an issue.
+    #[derive(cynic::InputObject, Clone, Debug, Default)]
+    pub struct NotificationEntityInput {
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub issue_id: Option<String>,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct NotificationMarkReadAllVariables {
+        pub read_at: DateTime,
+        pub input: NotificationEntityInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        graphql_type = "Mutation",
+        variables = "NotificationMarkReadAllVariables"
+    )]
+    pub struct NotificationMarkReadAll {
+        #[arguments(readAt: $read_at, input: $input)]
+        pub notification_mark_read_all: NotificationBatchActionPayload,
+    }
+
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct NotificationSnoozeAllVariables {
+        pub snoozed_until_at: DateTime,
+        pub input: NotificationEntityInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        graphql_type = "Mutation",
+        variables = "NotificationSnoozeAllVariables"
+    )]
+    pub struct NotificationSnoozeAll {
+        #[arguments(snoozedUntilAt: $snoozed_until_at, input: $input)]
+        pub notification_snooze_all: NotificationBatchActionPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NotificationBatchActionPayload {
+        pub success: bool,
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+
+use linear_api::schema::{issue_list::Issue, issue_update::IssueUpdateInput, Nullable, Uuid};
+
+use crate::{
+    cmd::{issue::update::batch_update, CliCommand, CommonArgs, Format},
+    render::Render,
+    resolve, util,
+};
+
+use super::cycle_title;
+
+/// Move issues into a cycle.
+///
+/// The cycle is looked up in the team of each issue, so "current" moves
+/// issues of several teams into the current cycle of their team.
+#[derive(clap::Parser)]
+pub struct CmdCycleAddIssues {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Cycle number, "current", "next" or "previous".
+    pub cycle: String,
+
+    /// Issue IDs (eg: MYTEAM-123).
+    ///
+    /// Use "-" to read whitespace separated IDs from stdin.
+    #[clap(required = true)]
+    pub issue_ids: Vec<String>,
+}
+
+impl CliCommand for CmdCycleAddIssues {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        let mut issues = Vec::<Issue>::new();
+        for id in util::issue_ids(&self.issue_ids)? {
+            let issue = resolve::issue(&client, &id).await?;
+            if !issues.iter().any(|i| i.id == issue.id) {
+                issues.push(issue);
+            }
+        }
+        if issues.is_empty() {
+            bail!("no issues given");
+        }
+
+        // Cycles are scoped to a team: update the issues of each team together.
+        let mut teams = HashMap::<cynic::Id, (String, Vec<Uuid>)>::new();
+        for issue in &issues {
+            if !teams.contains_key(&issue.team.id) {
+                let cycle = resolve::cycle(&client, &issue.team, &self.cycle).await?;
+                let title = cycle_title(cycle.number, cycle.name.as_deref());
+                teams.insert(issue.team.id.clone(), (cycle.id.into_inner(), Vec::new()));
+                eprintln!("Moving {} issues to {}", issue.team.key, title);
+            }
+            let (_, ids) = teams.get_mut(&issue.team.id).unwrap();
+            ids.push(Uuid(issue.id.inner().to_string()));
+        }
+
+        let mut updated = Vec::with_capacity(issues.len());
+        for (cycle_id, ids) in teams.into_values() {
+            let input = IssueUpdateInput {
+                cycle_id: Some(Nullable::Value(cycle_id)),
+                ..Default::default()
+            };
+            updated.extend(batch_update(&client, &ids, &input).await?);
+        }
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Moved {} issues", updated.len());
+                let columns = Issue::default_list_fields();
+                println!("{}", Issue::render_list_table(&updated, &columns));
+            }
+            format => {
+                let columns = Issue::default_list_fields();
+                println!("{}", Issue::render_list(&updated, &columns, format)?);
+            }
+        }
+
+        Ok(())
+    }
+}
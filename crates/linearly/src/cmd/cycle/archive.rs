@@ -0,0 +1,51 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::cycles::{CycleArchive, CycleIdVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs},
+    resolve,
+};
+
+use super::cycle_title;
+
+/// Archive a cycle.
+#[derive(clap::Parser)]
+pub struct CmdCycleArchive {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Team of the cycle.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: String,
+
+    /// Cycle number, "current", "next" or "previous".
+    pub cycle: String,
+}
+
+impl CliCommand for CmdCycleArchive {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+        let cycle = resolve::cycle(&client, &team, &self.cycle).await?;
+        let title = cycle_title(cycle.number, cycle.name.as_deref());
+
+        let payload = client
+            .query(CycleArchive::build(CycleIdVariables {
+                id: cycle.id.into_inner(),
+            }))
+            .await
+            .with_context(|| format!("could not archive {}", title))?
+            .cycle_archive;
+        if !payload.success {
+            bail!("archiving the cycle failed");
+        }
+
+        eprintln!("Archived {} in team {}", title, team.key);
+        Ok(())
+    }
+}
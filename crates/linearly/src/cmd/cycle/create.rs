@@ -0,0 +1,81 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::cycles::{CycleCreate, CycleCreateInput, CycleCreateVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve, util,
+};
+
+use super::cycle_title;
+
+/// Create a new cycle.
+#[derive(clap::Parser)]
+pub struct CmdCycleCreate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Team of the cycle.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: String,
+
+    /// Start date (YYYY-MM-DD).
+    #[clap(long)]
+    pub starts: String,
+
+    /// End date (YYYY-MM-DD).
+    #[clap(long)]
+    pub ends: String,
+
+    /// Custom name of the cycle.
+    #[clap(long, short)]
+    pub name: Option<String>,
+
+    /// Cycle description.
+    #[clap(long, short)]
+    pub description: Option<String>,
+}
+
+impl CliCommand for CmdCycleCreate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+
+        let input = CycleCreateInput {
+            team_id: team.id.into_inner(),
+            starts_at: util::parse_date_time(&self.starts)?,
+            ends_at: util::parse_date_time(&self.ends)?,
+            name: self.name.clone(),
+            description: self.description.clone(),
+        };
+
+        let payload = client
+            .query(CycleCreate::build(CycleCreateVariables { input }))
+            .await
+            .context("could not create cycle")?
+            .cycle_create;
+
+        let cycle = match payload.cycle {
+            Some(cycle) if payload.success => cycle,
+            _ => bail!("cycle creation failed"),
+        };
+
+        match self.format.resolve() {
+            Format::Table => eprintln!(
+                "Created {} in team {}",
+                cycle_title(cycle.number, cycle.name.as_deref()),
+                cycle.team.key
+            ),
+            format => println!("{}", render::render_value(&cycle, format)?),
+        }
+
+        Ok(())
+    }
+}
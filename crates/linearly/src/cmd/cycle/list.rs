@@ -0,0 +1,93 @@
+use futures::TryStreamExt;
+
+use linear_api::schema::{
+    cycles::{Cycle, CycleList, CycleListVariables},
+    issue_list::BooleanComparator,
+    lookup::CycleFilter,
+};
+
+use crate::{
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::Render,
+    resolve,
+};
+
+use super::CycleTableColumns;
+
+/// List cycles, most recent first.
+#[derive(clap::Parser)]
+pub struct CmdCycleList {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<CycleTableColumns>,
+
+    /// Only list cycles of this team.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+
+    /// Only list the current and upcoming cycles.
+    #[clap(long)]
+    pub upcoming: bool,
+
+    /// Maximum number of cycles to return.
+    #[clap(long, default_value = "50")]
+    pub limit: usize,
+
+    /// Return all cycles, ignoring --limit.
+    #[clap(long)]
+    pub all: bool,
+}
+
+impl CliCommand for CmdCycleList {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<Cycle>("cycle")?;
+        let client = self.common.client()?;
+
+        let mut filter = CycleFilter::default();
+        if let Some(key) = &self.team {
+            let team = resolve::team(&client, key).await?;
+            filter.team = resolve::team_id_filter(&team);
+        }
+        if self.upcoming {
+            filter.is_past = Some(BooleanComparator::is_false());
+        }
+
+        let vars = CycleListVariables {
+            filter: Some(filter),
+            first: 0,
+            after: None,
+        };
+        let limit = (!self.all).then_some(self.limit);
+
+        let mut cycles: Vec<Cycle> = client
+            .paginate::<CycleList>(vars, limit)
+            .try_collect()
+            .await?;
+        cycles.sort_by(|a, b| {
+            b.starts_at
+                .0
+                .cmp(&a.starts_at.0)
+                .then_with(|| a.team.key.cmp(&b.team.key))
+        });
+
+        let output = match &template {
+            Some(template) => template.render_list(&cycles)?,
+            None => Cycle::render_list(&cycles, &columns, self.format)?,
+        };
+        println!("{}", output);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use comfy_table::{Attribute, Cell, Color};
+
+use linear_api::schema::{cycles::Cycle, DateTime};
+
+use crate::render::{format_date, Render};
+
+use super::CliCommand;
+
+pub mod add_issues;
+pub mod archive;
+pub mod create;
+pub mod list;
+pub mod update;
+pub mod view;
+
+#[derive(clap::Subcommand)]
+pub enum CmdCycle {
+    #[clap(alias = "ls")]
+    List(list::CmdCycleList),
+    View(view::CmdCycleView),
+    #[clap(alias = "new")]
+    Create(create::CmdCycleCreate),
+    Update(update::CmdCycleUpdate),
+    Archive(archive::CmdCycleArchive),
+    AddIssues(add_issues::CmdCycleAddIssues),
+}
+
+impl CliCommand for CmdCycle {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        match self {
+            CmdCycle::List(c) => c.run().await,
+            CmdCycle::View(c) => c.run().await,
+            CmdCycle::Create(c) => c.run().await,
+            CmdCycle::Update(c) => c.run().await,
+            CmdCycle::Archive(c) => c.run().await,
+            CmdCycle::AddIssues(c) => c.run().await,
+        }
+    }
+}
+
+/// Human readable cycle name (eg: "Cycle 12 (Launch)").
+pub fn cycle_title(number: f64, name: Option<&str>) -> String {
+    match name {
+        Some(name) if !name.is_empty() => format!("Cycle {} ({})", number, name),
+        _ => format!("Cycle {}", number),
+    }
+}
+
+/// Status of a cycle relative to now: current, upcoming, past or completed.
+pub fn cycle_status(
+    starts_at: &DateTime,
+    ends_at: &DateTime,
+    completed_at: Option<&DateTime>,
+) -> &'static str {
+    if completed_at.is_some() {
+        return "completed";
+    }
+    let now = time::OffsetDateTime::now_utc();
+    match (starts_at.parse(), ends_at.parse()) {
+        (Ok(starts), _) if now < starts => "upcoming",
+        (_, Ok(ends)) if now >= ends => "past",
+        _ => "current",
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum CycleTableColumns {
+    Team,
+    Number,
+    Id,
+    Name,
+    Description,
+    Starts,
+    Ends,
+    Completed,
+    Progress,
+    Status,
+}
+
+impl Render for Cycle {
+    type Fields = CycleTableColumns;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            CycleTableColumns::Team,
+            CycleTableColumns::Number,
+            CycleTableColumns::Name,
+            CycleTableColumns::Starts,
+            CycleTableColumns::Ends,
+            CycleTableColumns::Progress,
+            CycleTableColumns::Status,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            CycleTableColumns::Team,
+            CycleTableColumns::Number,
+            CycleTableColumns::Name,
+            CycleTableColumns::Description,
+            CycleTableColumns::Starts,
+            CycleTableColumns::Ends,
+            CycleTableColumns::Completed,
+            CycleTableColumns::Progress,
+            CycleTableColumns::Status,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        Self::default_list_fields()
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            CycleTableColumns::Team => Cell::new("Team"),
+            CycleTableColumns::Number => Cell::new("Cycle").add_attribute(Attribute::Bold),
+            CycleTableColumns::Id => Cell::new("ID"),
+            CycleTableColumns::Name => Cell::new("Name"),
+            CycleTableColumns::Description => Cell::new("Description"),
+            CycleTableColumns::Starts => Cell::new("Starts"),
+            CycleTableColumns::Ends => Cell::new("Ends"),
+            CycleTableColumns::Completed => Cell::new("Completed"),
+            CycleTableColumns::Progress => Cell::new("Progress"),
+            CycleTableColumns::Status => Cell::new("Status"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        match field {
+            CycleTableColumns::Team => Cell::new(&item.team.key),
+            CycleTableColumns::Number => Cell::new(item.number),
+            CycleTableColumns::Id => Cell::new(item.id.inner()),
+            CycleTableColumns::Name => Cell::new(item.name.as_deref().unwrap_or_default()),
+            CycleTableColumns::Description => {
+                Cell::new(item.description.as_deref().unwrap_or_default())
+            }
+            CycleTableColumns::Starts => Cell::new(format_date(&item.starts_at)),
+            CycleTableColumns::Ends => Cell::new(format_date(&item.ends_at)),
+            CycleTableColumns::Completed => Cell::new(
+                item.completed_at
+                    .as_ref()
+                    .map(format_date)
+                    .unwrap_or_default(),
+            ),
+            CycleTableColumns::Progress => Cell::new(format!("{:.0}%", item.progress * 100.0)),
+            CycleTableColumns::Status => {
+                let status =
+                    cycle_status(&item.starts_at, &item.ends_at, item.completed_at.as_ref());
+                let cell = Cell::new(status);
+                match status {
+                    "current" => cell.fg(Color::Green).add_attribute(Attribute::Bold),
+                    "upcoming" => cell.fg(Color::Cyan),
+                    _ => cell,
+                }
+            }
+        }
+    }
+}
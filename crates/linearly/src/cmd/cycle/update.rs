@@ -0,0 +1,103 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::cycles::{CycleUpdateInput, CycleUpdateMutation, CycleUpdateVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve, util,
+};
+
+use super::cycle_title;
+
+/// Update the name, description or dates of a cycle.
+#[derive(clap::Parser)]
+pub struct CmdCycleUpdate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Team of the cycle.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: String,
+
+    /// Cycle number, "current", "next" or "previous".
+    pub cycle: String,
+
+    /// New custom name.
+    #[clap(long, short)]
+    pub name: Option<String>,
+
+    /// New description.
+    #[clap(long, short)]
+    pub description: Option<String>,
+
+    /// New start date (YYYY-MM-DD).
+    #[clap(long)]
+    pub starts: Option<String>,
+
+    /// New end date (YYYY-MM-DD).
+    #[clap(long)]
+    pub ends: Option<String>,
+}
+
+impl CliCommand for CmdCycleUpdate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        if self.name.is_none()
+            && self.description.is_none()
+            && self.starts.is_none()
+            && self.ends.is_none()
+        {
+            bail!("nothing to update: specify at least one field to change (see --help)");
+        }
+
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+        let cycle = resolve::cycle(&client, &team, &self.cycle).await?;
+
+        let input = CycleUpdateInput {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            starts_at: self
+                .starts
+                .as_deref()
+                .map(util::parse_date_time)
+                .transpose()?,
+            ends_at: self
+                .ends
+                .as_deref()
+                .map(util::parse_date_time)
+                .transpose()?,
+        };
+
+        let payload = client
+            .query(CycleUpdateMutation::build(CycleUpdateVariables {
+                id: cycle.id.into_inner(),
+                input,
+            }))
+            .await
+            .with_context(|| format!("could not update cycle {}", self.cycle))?
+            .cycle_update;
+
+        let cycle = match payload.cycle {
+            Some(cycle) if payload.success => cycle,
+            _ => bail!("cycle update failed"),
+        };
+
+        match self.format.resolve() {
+            Format::Table => eprintln!(
+                "Updated {} in team {}",
+                cycle_title(cycle.number, cycle.name.as_deref()),
+                cycle.team.key
+            ),
+            format => println!("{}", render::render_value(&cycle, format)?),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt::Write;
+
+use anyhow::Context;
+use colored::Colorize;
+use cynic::QueryBuilder;
+
+use linear_api::schema::cycles::{CycleDetail, CycleGet, CycleIdVariables, CycleIssue};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render::{self, format_date, markdown},
+    resolve,
+};
+
+use super::{cycle_status, cycle_title};
+
+/// Height of the burndown chart in lines.
+const CHART_HEIGHT: usize = 10;
+
+/// Show a cycle with its scope, issues grouped by state and a burndown
+/// chart.
+#[derive(clap::Parser)]
+pub struct CmdCycleView {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    /// Team of the cycle.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: String,
+
+    /// Cycle number, "current", "next" or "previous".
+    #[clap(default_value = "current")]
+    pub cycle: String,
+}
+
+impl CliCommand for CmdCycleView {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+        let cycle = resolve::cycle(&client, &team, &self.cycle).await?;
+
+        let cycle = client
+            .query(CycleGet::build(CycleIdVariables {
+                id: cycle.id.into_inner(),
+            }))
+            .await
+            .with_context(|| format!("could not load cycle {}", self.cycle))?
+            .cycle;
+
+        if let Some(template) = &template {
+            println!("{}", template.render(&cycle)?);
+        } else {
+            match self.format.resolve() {
+                Format::Table => {
+                    markdown::load_team_keys(&client).await?;
+                    print!("{}", render_cycle(&cycle));
+                }
+                format => println!("{}", render::render_value(&cycle, format)?),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn last(history: &[f64]) -> f64 {
+    history.last().copied().unwrap_or_default()
+}
+
+fn section(out: &mut String, title: &str) {
+    writeln!(out).unwrap();
+    writeln!(out, "{}", title.bold().underline()).unwrap();
+}
+
+/// Render a cycle as a multi-section text view.
+fn render_cycle(cycle: &CycleDetail) -> String {
+    let mut out = String::new();
+
+    let title = cycle_title(cycle.number, cycle.name.as_deref());
+    writeln!(out, "{} {}", cycle.team.key.bold(), title.bold()).unwrap();
+
+    let status = cycle_status(
+        &cycle.starts_at,
+        &cycle.ends_at,
+        cycle.completed_at.as_ref(),
+    );
+    let points_used = cycle.scope_history.iter().any(|v| *v > 0.0);
+    let mut fields: Vec<(&str, String)> = vec![
+        (
+            "Dates",
+            format!(
+                "{} - {}",
+                format_date(&cycle.starts_at),
+                format_date(&cycle.ends_at)
+            ),
+        ),
+        ("Status", status.to_string()),
+        ("Progress", format!("{:.0}%", cycle.progress * 100.0)),
+        (
+            "Issues",
+            format!(
+                "{} of {} completed",
+                last(&cycle.completed_issue_count_history),
+                last(&cycle.issue_count_history)
+            ),
+        ),
+    ];
+    if points_used {
+        fields.push((
+            "Scope",
+            format!(
+                "{} of {} points completed, {} in progress",
+                last(&cycle.completed_scope_history),
+                last(&cycle.scope_history),
+                last(&cycle.in_progress_scope_history)
+            ),
+        ));
+    }
+    if let Some(at) = &cycle.completed_at {
+        fields.push(("Completed", format_date(at)));
+    }
+
+    writeln!(out).unwrap();
+    let width = fields
+        .iter()
+        .map(|(k, _)| k.len())
+        .max()
+        .unwrap_or_default();
+    for (key, value) in &fields {
+        writeln!(out, "{}  {}", format!("{:>width$}", key).dimmed(), value).unwrap();
+    }
+
+    if let Some(description) = cycle.description.as_deref().map(str::trim) {
+        if !description.is_empty() {
+            section(&mut out, "Description");
+            writeln!(out, "{}", markdown::render(description)).unwrap();
+        }
+    }
+
+    section(&mut out, "Issues");
+    if cycle.issues.nodes.is_empty() {
+        writeln!(out, "{}", "No issues.".dimmed()).unwrap();
+    }
+    for (state, issues) in group_by_state(&cycle.issues.nodes) {
+        writeln!(
+            out,
+            "\n{} {}",
+            state.bold(),
+            format!("({})", issues.len()).dimmed()
+        )
+        .unwrap();
+        for issue in issues {
+            write!(out, "  {} {}", issue.identifier.bold(), issue.title).unwrap();
+            if let Some(user) = &issue.assignee {
+                write!(out, " {}", format!("@{}", user.display_name).dimmed()).unwrap();
+            }
+            writeln!(out).unwrap();
+        }
+    }
+
+    // Without estimates, the issue counts are the more useful scope.
+    let (scope, completed) = if points_used {
+        (&cycle.scope_history, &cycle.completed_scope_history)
+    } else {
+        (
+            &cycle.issue_count_history,
+            &cycle.completed_issue_count_history,
+        )
+    };
+    let days = match (cycle.starts_at.parse(), cycle.ends_at.parse()) {
+        (Ok(starts), Ok(ends)) => (ends - starts).whole_days().max(1) as usize,
+        _ => scope.len(),
+    };
+    if !scope.is_empty() {
+        section(
+            &mut out,
+            if points_used {
+                "Burndown (points)"
+            } else {
+                "Burndown (issues)"
+            },
+        );
+        out.push_str(&burndown(scope, completed, days, CHART_HEIGHT));
+        writeln!(
+            out,
+            "{}",
+            format!("Remaining work: {}   Ideal: {}", "█".cyan(), "·".dimmed()).dimmed()
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+/// Group issues by workflow state, in workflow order.
+fn group_by_state(issues: &[CycleIssue]) -> Vec<(&str, Vec<&CycleIssue>)> {
+    let mut sorted = issues.iter().collect::<Vec<_>>();
+    sorted.sort_by(|a, b| {
+        resolve::state_type_order(&a.state.type_)
+            .cmp(&resolve::state_type_order(&b.state.type_))
+            .then(a.state.position.total_cmp(&b.state.position))
+    });
+
+    let mut groups = Vec::<(&str, Vec<&CycleIssue>)>::new();
+    for issue in sorted {
+        match groups.last_mut() {
+            Some((name, issues)) if *name == issue.state.name => issues.push(issue),
+            _ => groups.push((&issue.state.name, vec![issue])),
+        }
+    }
+    groups
+}
+
+/// Render an ASCII burndown chart.
+///
+/// `scope` and `completed` contain one value per day since the cycle started.
+/// Each day is drawn as a column with the remaining work, next to the ideal
+/// line going from the initial scope to zero at the end of the cycle.
+fn burndown(scope: &[f64], completed: &[f64], days: usize, height: usize) -> String {
+    let days = days.max(scope.len()).max(2);
+    let remaining = scope
+        .iter()
+        .enumerate()
+        .map(|(day, scope)| scope - completed.get(day).copied().unwrap_or_default())
+        .collect::<Vec<_>>();
+    let start = scope.first().copied().unwrap_or_default();
+    let ideal = (0..days)
+        .map(|day| start * (1.0 - day as f64 / (days - 1) as f64))
+        .collect::<Vec<_>>();
+
+    let max = scope.iter().copied().fold(start, f64::max).max(1.0);
+    let step = max / height as f64;
+    let label_width = format!("{}", max.ceil()).len();
+
+    let mut out = String::new();
+    for row in (1..=height).rev() {
+        let low = step * (row - 1) as f64;
+        let high = step * row as f64;
+        let label = if row == height {
+            format!("{}", max.ceil())
+        } else {
+            String::new()
+        };
+        write!(out, "{:>label_width$} ┤", label).unwrap();
+        for (day, ideal) in ideal.iter().enumerate() {
+            let bar = remaining.get(day).is_some_and(|v| *v >= (low + high) / 2.0);
+            if bar {
+                write!(out, "{}", "██".cyan()).unwrap();
+            } else if *ideal > low && *ideal <= high {
+                write!(out, "{} ", "·".dimmed()).unwrap();
+            } else {
+                out.push_str("  ");
+            }
+        }
+        writeln!(out).unwrap();
+    }
+    writeln!(out, "{:>label_width$} └{}", 0, "─".repeat(days * 2)).unwrap();
+    let end = format!("day {}", days);
+    writeln!(
+        out,
+        "{:>label_width$}  day 1{:>width$}",
+        "",
+        end,
+        width = (days * 2).saturating_sub(5)
+    )
+    .unwrap();
+    out
+}
@@ -0,0 +1,44 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::notifications::{NotificationArchive, NotificationIdVariables};
+
+use crate::cmd::{CliCommand, CommonArgs};
+
+use super::{describe, find};
+
+/// Archive notifications, removing them from the inbox.
+#[derive(clap::Parser)]
+pub struct CmdInboxArchive {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Notification IDs or issue identifiers (eg: MYTEAM-123).
+    /// Use "-" to read them from stdin.
+    #[clap(required = true)]
+    pub ids: Vec<String>,
+}
+
+impl CliCommand for CmdInboxArchive {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        for notification in find(&client, &self.ids).await? {
+            let payload = client
+                .query(NotificationArchive::build(NotificationIdVariables {
+                    id: notification.fields.id.inner().to_string(),
+                }))
+                .await
+                .with_context(|| format!("could not archive {}", describe(&notification)))?
+                .notification_archive;
+            if !payload.success {
+                bail!("archiving the notification failed");
+            }
+            eprintln!("Archived {}", describe(&notification));
+        }
+
+        Ok(())
+    }
+}
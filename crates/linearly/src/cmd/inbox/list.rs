@@ -0,0 +1,105 @@
+use anyhow::bail;
+use futures::{future, StreamExt, TryStreamExt};
+
+use linear_api::schema::notifications::{
+    Notification, NotificationList, NotificationListVariables,
+};
+
+use crate::{
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{self, Render},
+};
+
+use super::{find, is_snoozed, open, NotificationTableColumns};
+
+/// List notifications, newest first.
+///
+/// Unread notifications are marked with a dot. Snoozed notifications are
+/// hidden until the snooze ends.
+#[derive(clap::Parser)]
+pub struct CmdInboxList {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<NotificationTableColumns>,
+
+    /// Only list unread notifications.
+    #[clap(long)]
+    pub unread: bool,
+
+    /// Include snoozed notifications.
+    #[clap(long)]
+    pub snoozed: bool,
+
+    /// Include archived notifications.
+    #[clap(long)]
+    pub archived: bool,
+
+    /// Maximum number of notifications to return.
+    #[clap(long, default_value = "50")]
+    pub limit: usize,
+
+    /// Return all notifications, ignoring --limit.
+    #[clap(long)]
+    pub all: bool,
+
+    /// Open the issue of a notification in the browser instead of listing.
+    /// Expects a notification ID or an issue identifier (eg: MYTEAM-123).
+    #[clap(long, value_name = "ID")]
+    pub open: Option<String>,
+}
+
+impl CliCommand for CmdInboxList {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<Notification>("notification")?;
+        let client = self.common.client()?;
+
+        if let Some(id) = &self.open {
+            let notifications = find(&client, std::slice::from_ref(id)).await?;
+            let Some(notification) = notifications.first() else {
+                bail!("no notification found for '{}'", id);
+            };
+            return open(notification);
+        }
+
+        let vars = NotificationListVariables {
+            include_archived: self.archived.then_some(true),
+            first: 0,
+            after: None,
+        };
+        let limit = if self.all { usize::MAX } else { self.limit };
+
+        // Notifications are filtered locally, so pages are fetched until
+        // enough of them match.
+        let notifications = client
+            .paginate::<NotificationList>(vars, None)
+            .try_filter(|n| {
+                let keep = (!self.unread || n.fields.read_at.is_none())
+                    && (self.snoozed || !is_snoozed(n));
+                future::ready(keep)
+            })
+            .take(limit);
+        if let Some(template) = &template {
+            return template.print_stream(notifications).await;
+        }
+        if let Format::Ndjson = self.format {
+            return render::stream_ndjson(notifications).await;
+        }
+        let notifications: Vec<Notification> = notifications.try_collect().await?;
+
+        let output = Notification::render_list(&notifications, &columns, self.format)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,454 @@
+use std::process::Stdio;
+
+use anyhow::bail;
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color};
+use futures::TryStreamExt;
+use time::format_description::well_known::Rfc3339;
+
+use linear_api::{
+    schema::{
+        notifications::{
+            Notification, NotificationEntityInput, NotificationList, NotificationListVariables,
+        },
+        DateTime,
+    },
+    Client,
+};
+
+use crate::{
+    render::{format_date, Render},
+    util,
+};
+
+use super::CliCommand;
+
+pub mod archive;
+pub mod list;
+pub mod read;
+pub mod snooze;
+pub mod unread;
+
+/// List the notifications in your inbox, or manage them with a subcommand.
+///
+/// Notifications can be given by ID, or by the identifier of their issue
+/// (eg: MYTEAM-123), which selects all notifications of that issue.
+#[derive(clap::Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
+pub struct CmdInbox {
+    #[clap(subcommand)]
+    pub cmd: Option<CmdInboxAction>,
+
+    #[clap(flatten)]
+    pub list: list::CmdInboxList,
+}
+
+#[derive(clap::Subcommand)]
+pub enum CmdInboxAction {
+    #[clap(alias = "ls")]
+    List(list::CmdInboxList),
+    Read(read::CmdInboxRead),
+    Unread(unread::CmdInboxUnread),
+    Archive(archive::CmdInboxArchive),
+    Snooze(snooze::CmdInboxSnooze),
+}
+
+impl CliCommand for CmdInbox {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        match self.cmd {
+            None => self.list.run().await,
+            Some(CmdInboxAction::List(c)) => c.run().await,
+            Some(CmdInboxAction::Read(c)) => c.run().await,
+            Some(CmdInboxAction::Unread(c)) => c.run().await,
+            Some(CmdInboxAction::Archive(c)) => c.run().await,
+            Some(CmdInboxAction::Snooze(c)) => c.run().await,
+        }
+    }
+}
+
+/// Short description of the notification type, eg: "assigned" for
+/// "issueAssignedToYou".
+pub fn notification_kind(kind: &str) -> &str {
+    match kind {
+        "issueAssignedToYou" => "assigned",
+        "issueUnassignedFromYou" => "unassigned",
+        "issueMention"
+        | "issueCommentMention"
+        | "issueDescriptionMention"
+        | "projectUpdateMentionPrompt" => "mentioned",
+        "issueNewComment" | "issueCommentReaction" | "issueThreadResolved" => "comment",
+        "issueStatusChanged" | "issueStatusChangedAll" | "issueBlocking" | "issueUnblocked" => {
+            "status"
+        }
+        "issueCreated" => "created",
+        "issueDue" => "due",
+        "issueSubscribed" => "subscribed",
+        "projectUpdateCreated"
+        | "projectUpdatePrompt"
+        | "projectUpdateNewComment"
+        | "projectUpdateReaction" => "project update",
+        other => other,
+    }
+}
+
+/// The issue key or project name a notification is about.
+pub fn notification_subject(notification: &Notification) -> &str {
+    if let Some(issue) = &notification.issue {
+        &issue.identifier
+    } else if let Some(project) = &notification.project {
+        &project.name
+    } else {
+        ""
+    }
+}
+
+/// Whether a notification is snoozed until a time in the future.
+pub fn is_snoozed(notification: &Notification) -> bool {
+    let now = time::OffsetDateTime::now_utc();
+    notification
+        .fields
+        .snoozed_until_at
+        .as_ref()
+        .and_then(|until| until.parse().ok())
+        .is_some_and(|until| until > now)
+}
+
+/// The current time, for the `readAt` and `snoozedUntilAt` inputs.
+pub fn now() -> DateTime {
+    DateTime(time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap())
+}
+
+/// Parse the end of a snooze: a duration like 3h, 2d or 1w, or a date
+/// (YYYY-MM-DD).
+pub fn parse_snooze_until(value: &str) -> Result<DateTime, anyhow::Error> {
+    if value.contains('-') {
+        return util::parse_date_time(value);
+    }
+    let invalid = || {
+        anyhow::anyhow!(
+            "invalid duration '{}': expected a number of hours, days or weeks (eg: 3h, 2d, 1w) or a date (YYYY-MM-DD)",
+            value
+        )
+    };
+    let Some(unit) = value.chars().last() else {
+        return Err(invalid());
+    };
+    let amount: i64 = value[..value.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    let seconds = match unit {
+        'h' => 3600,
+        'd' => 86_400,
+        'w' => 604_800,
+        _ => return Err(invalid()),
+    };
+    let until = amount
+        .checked_mul(seconds)
+        .and_then(|seconds| {
+            time::OffsetDateTime::now_utc().checked_add(time::Duration::seconds(seconds))
+        })
+        .ok_or_else(invalid)?;
+    let until = until.format(&Rfc3339).map_err(|_| invalid())?;
+    Ok(DateTime(until))
+}
+
+/// All notifications in the inbox, newest first.
+pub async fn inbox(client: &Client) -> Result<Vec<Notification>, anyhow::Error> {
+    let vars = NotificationListVariables {
+        include_archived: None,
+        first: 0,
+        after: None,
+    };
+    let notifications = client
+        .paginate::<NotificationList>(vars, None)
+        .try_collect()
+        .await?;
+    Ok(notifications)
+}
+
+/// Find the notifications for notification IDs or issue identifiers.
+pub async fn find(client: &Client, ids: &[String]) -> Result<Vec<Notification>, anyhow::Error> {
+    let ids = util::issue_ids(ids)?;
+    let mut notifications = inbox(client).await?;
+    for id in &ids {
+        if !notifications.iter().any(|n| matches_id(n, id)) {
+            bail!("no notification found for '{}'", id);
+        }
+    }
+    notifications.retain(|n| ids.iter().any(|id| matches_id(n, id)));
+    Ok(notifications)
+}
+
+fn matches_id(notification: &Notification, id: &str) -> bool {
+    notification.fields.id.inner() == id
+        || notification
+            .issue
+            .as_ref()
+            .is_some_and(|issue| issue.identifier.eq_ignore_ascii_case(id))
+}
+
+/// Inputs for the batch mutations, which also apply to related
+/// notifications: one per issue, and one per other notification.
+pub fn entity_inputs(notifications: &[Notification]) -> Vec<NotificationEntityInput> {
+    let mut inputs: Vec<NotificationEntityInput> = Vec::new();
+    for notification in notifications {
+        let input = match &notification.issue {
+            Some(issue) => NotificationEntityInput {
+                issue_id: Some(issue.id.inner().to_string()),
+                ..Default::default()
+            },
+            None => NotificationEntityInput {
+                id: Some(notification.fields.id.inner().to_string()),
+                ..Default::default()
+            },
+        };
+        let duplicate =
+            input.issue_id.is_some() && inputs.iter().any(|other| other.issue_id == input.issue_id);
+        if !duplicate {
+            inputs.push(input);
+        }
+    }
+    inputs
+}
+
+/// Open the issue or project of a notification in the browser.
+pub fn open(notification: &Notification) -> Result<(), anyhow::Error> {
+    let url = &notification.fields.url;
+    eprintln!("Opening URL {} in the browser...", url);
+    open::commands(url)[0]
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    Ok(())
+}
+
+/// One line summary of a notification for status messages.
+pub fn describe(notification: &Notification) -> String {
+    let subject = notification_subject(notification);
+    if subject.is_empty() {
+        notification.fields.title.clone()
+    } else {
+        format!("{} ({})", subject.bold(), notification.fields.title)
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum NotificationTableColumns {
+    Unread,
+    Id,
+    Subject,
+    Type,
+    Title,
+    Subtitle,
+    Actor,
+    Team,
+    Created,
+    Read,
+    Snoozed,
+    Url,
+}
+
+impl Render for Notification {
+    type Fields = NotificationTableColumns;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            NotificationTableColumns::Unread,
+            NotificationTableColumns::Subject,
+            NotificationTableColumns::Type,
+            NotificationTableColumns::Title,
+            NotificationTableColumns::Actor,
+            NotificationTableColumns::Created,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            NotificationTableColumns::Unread,
+            NotificationTableColumns::Id,
+            NotificationTableColumns::Subject,
+            NotificationTableColumns::Type,
+            NotificationTableColumns::Title,
+            NotificationTableColumns::Subtitle,
+            NotificationTableColumns::Actor,
+            NotificationTableColumns::Created,
+            NotificationTableColumns::Snoozed,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        Self::default_list_fields()
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            NotificationTableColumns::Unread => Cell::new(""),
+            NotificationTableColumns::Id => Cell::new("ID"),
+            NotificationTableColumns::Subject => {
+                Cell::new("Subject").add_attribute(Attribute::Bold)
+            }
+            NotificationTableColumns::Type => Cell::new("Type"),
+            NotificationTableColumns::Title => Cell::new("Title"),
+            NotificationTableColumns::Subtitle => Cell::new("Subtitle"),
+            NotificationTableColumns::Actor => Cell::new("Actor"),
+            NotificationTableColumns::Team => Cell::new("Team"),
+            NotificationTableColumns::Created => Cell::new("Created"),
+            NotificationTableColumns::Read => Cell::new("Read"),
+            NotificationTableColumns::Snoozed => Cell::new("Snoozed until"),
+            NotificationTableColumns::Url => Cell::new("URL"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        let unread = item.fields.read_at.is_none();
+        match field {
+            NotificationTableColumns::Unread if unread => Cell::new("●").fg(Color::Blue),
+            NotificationTableColumns::Unread => Cell::new(""),
+            NotificationTableColumns::Id => Cell::new(item.fields.id.inner()),
+            NotificationTableColumns::Subject => {
+                let cell = Cell::new(notification_subject(item));
+                if unread {
+                    cell.add_attribute(Attribute::Bold)
+                } else {
+                    cell
+                }
+            }
+            NotificationTableColumns::Type => Cell::new(notification_kind(&item.fields.kind)),
+            NotificationTableColumns::Title => {
+                let cell = Cell::new(&item.fields.title);
+                if unread {
+                    cell.add_attribute(Attribute::Bold)
+                } else {
+                    cell.fg(Color::DarkGrey)
+                }
+            }
+            NotificationTableColumns::Subtitle => Cell::new(&item.fields.subtitle),
+            NotificationTableColumns::Actor => Cell::new(
+                item.fields
+                    .actor
+                    .as_ref()
+                    .map(|u| u.display_name.as_str())
+                    .unwrap_or_default(),
+            ),
+            NotificationTableColumns::Team => Cell::new(
+                item.team
+                    .as_ref()
+                    .map(|t| t.key.as_str())
+                    .unwrap_or_default(),
+            ),
+            NotificationTableColumns::Created => Cell::new(format_date(&item.fields.created_at)),
+            NotificationTableColumns::Read => Cell::new(
+                item.fields
+                    .read_at
+                    .as_ref()
+                    .map(format_date)
+                    .unwrap_or_default(),
+            ),
+            NotificationTableColumns::Snoozed if is_snoozed(item) => Cell::new(
+                item.fields
+                    .snoozed_until_at
+                    .as_ref()
+                    .map(format_date)
+                    .unwrap_or_default(),
+            ),
+            NotificationTableColumns::Snoozed => Cell::new(""),
+            NotificationTableColumns::Url => Cell::new(&item.fields.url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linear_api::schema::{lookup::IssueRef, notifications::NotificationFields};
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    fn hours_from_now(value: &str) -> i64 {
+        let until: OffsetDateTime = parse_snooze_until(value).unwrap().parse().unwrap();
+        let seconds = (until - OffsetDateTime::now_utc()).whole_seconds();
+        // Round up, the parsed time was taken slightly earlier.
+        (seconds + 60) / 3600
+    }
+
+    #[test]
+    fn snooze_durations() {
+        assert_eq!(hours_from_now("3h"), 3);
+        assert_eq!(hours_from_now("2d"), 48);
+        assert_eq!(hours_from_now("1w"), 168);
+    }
+
+    #[test]
+    fn snooze_until_date() {
+        let until = parse_snooze_until("2030-01-02").unwrap();
+        assert_eq!(until.0, "2030-01-02T00:00:00.000Z");
+    }
+
+    #[test]
+    fn invalid_snooze_durations() {
+        // Bad units, overflowing amounts, and amounts too far in the future.
+        for value in [
+            "",
+            "3",
+            "h",
+            "3m",
+            "3 h",
+            "9223372036854775807w",
+            "99999999999d",
+        ] {
+            let err = parse_snooze_until(value).unwrap_err().to_string();
+            assert!(err.starts_with("invalid duration"), "{}: {}", value, err);
+        }
+        assert!(parse_snooze_until("2030-13-01").is_err());
+    }
+
+    fn notification(id: &str, issue: Option<&str>) -> Notification {
+        Notification {
+            fields: NotificationFields {
+                id: cynic::Id::new(id),
+                kind: "issueNewComment".to_string(),
+                title: String::new(),
+                subtitle: String::new(),
+                url: String::new(),
+                actor: None,
+                created_at: DateTime("2026-01-01T00:00:00.000Z".to_string()),
+                read_at: None,
+                snoozed_until_at: None,
+                archived_at: None,
+            },
+            issue: issue.map(|id| IssueRef {
+                id: cynic::Id::new(id),
+                identifier: id.to_string(),
+                title: String::new(),
+            }),
+            team: None,
+            project: None,
+        }
+    }
+
+    #[test]
+    fn entity_inputs_per_issue() {
+        let notifications = [
+            notification("n1", Some("ENG-1")),
+            notification("n2", Some("ENG-1")),
+            notification("n3", None),
+            notification("n4", None),
+            notification("n5", Some("ENG-2")),
+        ];
+        let inputs = entity_inputs(&notifications)
+            .into_iter()
+            .map(|input| (input.id, input.issue_id))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            inputs,
+            [
+                (None, Some("ENG-1".to_string())),
+                (Some("n3".to_string()), None),
+                (Some("n4".to_string()), None),
+                (None, Some("ENG-2".to_string())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::{
+    notifications::{
+        NotificationMarkReadAll, NotificationMarkReadAllVariables, NotificationUpdate,
+        NotificationUpdateInput, NotificationUpdateVariables,
+    },
+    Nullable,
+};
+
+use crate::cmd::{CliCommand, CommonArgs};
+
+use super::{describe, entity_inputs, find, inbox, now, open};
+
+/// Mark notifications as read.
+#[derive(clap::Parser)]
+pub struct CmdInboxRead {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Notification IDs or issue identifiers (eg: MYTEAM-123).
+    /// Use "-" to read them from stdin.
+    #[clap(required_unless_present = "all", conflicts_with = "all")]
+    pub ids: Vec<String>,
+
+    /// Mark all notifications as read.
+    #[clap(long)]
+    pub all: bool,
+
+    /// Also open the issues of the notifications in the browser.
+    #[clap(long, conflicts_with = "all")]
+    pub open: bool,
+}
+
+impl CliCommand for CmdInboxRead {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        if self.all {
+            let mut unread = inbox(&client).await?;
+            unread.retain(|n| n.fields.read_at.is_none());
+            for input in entity_inputs(&unread) {
+                let payload = client
+                    .query(NotificationMarkReadAll::build(
+                        NotificationMarkReadAllVariables {
+                            read_at: now(),
+                            input,
+                        },
+                    ))
+                    .await
+                    .context("could not mark notifications as read")?
+                    .notification_mark_read_all;
+                if !payload.success {
+                    bail!("marking notifications as read failed");
+                }
+            }
+            eprintln!("Marked {} notifications as read", unread.len());
+            return Ok(());
+        }
+
+        for notification in find(&client, &self.ids).await? {
+            let payload = client
+                .query(NotificationUpdate::build(NotificationUpdateVariables {
+                    id: notification.fields.id.inner().to_string(),
+                    input: NotificationUpdateInput {
+                        read_at: Some(Nullable::Value(now())),
+                        ..Default::default()
+                    },
+                }))
+                .await
+                .with_context(|| format!("could not mark {} as read", describe(&notification)))?
+                .notification_update;
+            if !payload.success {
+                bail!("marking the notification as read failed");
+            }
+            eprintln!("Marked {} as read", describe(&notification));
+            if self.open {
+                open(&notification)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::{
+    notifications::{
+        NotificationSnoozeAll, NotificationSnoozeAllVariables, NotificationUpdate,
+        NotificationUpdateInput, NotificationUpdateVariables,
+    },
+    Nullable,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs},
+    render::format_date,
+};
+
+use super::{describe, entity_inputs, find, inbox, is_snoozed, parse_snooze_until};
+
+/// Snooze notifications, hiding them from the inbox for a while.
+#[derive(clap::Parser)]
+pub struct CmdInboxSnooze {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// How long to snooze: a number of hours, days or weeks (eg: 3h, 2d, 1w),
+    /// or the date (YYYY-MM-DD) the notifications should return.
+    pub duration: String,
+
+    /// Notification IDs or issue identifiers (eg: MYTEAM-123).
+    /// Use "-" to read them from stdin.
+    #[clap(required_unless_present = "all", conflicts_with = "all")]
+    pub ids: Vec<String>,
+
+    /// Snooze all notifications in the inbox.
+    #[clap(long)]
+    pub all: bool,
+}
+
+impl CliCommand for CmdInboxSnooze {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let until = parse_snooze_until(&self.duration)?;
+        let client = self.common.client()?;
+
+        if self.all {
+            let mut notifications = inbox(&client).await?;
+            notifications.retain(|n| !is_snoozed(n));
+            for input in entity_inputs(&notifications) {
+                let payload = client
+                    .query(NotificationSnoozeAll::build(
+                        NotificationSnoozeAllVariables {
+                            snoozed_until_at: until.clone(),
+                            input,
+                        },
+                    ))
+                    .await
+                    .context("could not snooze notifications")?
+                    .notification_snooze_all;
+                if !payload.success {
+                    bail!("snoozing notifications failed");
+                }
+            }
+            eprintln!(
+                "Snoozed {} notifications until {}",
+                notifications.len(),
+                format_date(&until)
+            );
+            return Ok(());
+        }
+
+        for notification in find(&client, &self.ids).await? {
+            let payload = client
+                .query(NotificationUpdate::build(NotificationUpdateVariables {
+                    id: notification.fields.id.inner().to_string(),
+                    input: NotificationUpdateInput {
+                        snoozed_until_at: Some(Nullable::Value(until.clone())),
+                        ..Default::default()
+                    },
+                }))
+                .await
+                .with_context(|| format!("could not snooze {}", describe(&notification)))?
+                .notification_update;
+            if !payload.success {
+                bail!("snoozing the notification failed");
+            }
+            eprintln!(
+                "Snoozed {} until {}",
+                describe(&notification),
+                format_date(&until)
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::{
+    notifications::{NotificationUpdate, NotificationUpdateInput, NotificationUpdateVariables},
+    Nullable,
+};
+
+use crate::cmd::{CliCommand, CommonArgs};
+
+use super::{describe, find};
+
+/// Mark notifications as unread.
+#[derive(clap::Parser)]
+pub struct CmdInboxUnread {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Notification IDs or issue identifiers (eg: MYTEAM-123).
+    /// Use "-" to read them from stdin.
+    #[clap(required = true)]
+    pub ids: Vec<String>,
+}
+
+impl CliCommand for CmdInboxUnread {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        for notification in find(&client, &self.ids).await? {
+            let payload = client
+                .query(NotificationUpdate::build(NotificationUpdateVariables {
+                    id: notification.fields.id.inner().to_string(),
+                    input: NotificationUpdateInput {
+                        read_at: Some(Nullable::Null),
+                        ..Default::default()
+                    },
+                }))
+                .await
+                .with_context(|| format!("could not mark {} as unread", describe(&notification)))?
+                .notification_update;
+            if !payload.success {
+                bail!("marking the notification as unread failed");
+            }
+            eprintln!("Marked {} as unread", describe(&notification));
+        }
+
+        Ok(())
+    }
+}
@@ -56,10 +56,9 @@ impl CliCommand for CmdIssueCheckout {
         let issue = self
             .common
             .client()?
-            .run(op)
-            .await?
-            .data
-            .with_context(|| format!("issue {} not found", self.issue_id))?
+            .query(op)
+            .await
+            .with_context(|| format!("could not load issue {}", self.issue_id))?
             .issue;
 
         let branch = issue.branch_name;
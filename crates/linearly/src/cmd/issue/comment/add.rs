@@ -0,0 +1,67 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::comments::{CommentCreate, CommentCreateInput, CommentCreateVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve,
+};
+
+use super::BodyArgs;
+
+/// Add a comment to an issue.
+///
+/// Without --body or --body-file, the comment is written in $VISUAL/$EDITOR.
+#[derive(clap::Parser)]
+pub struct CmdCommentAdd {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub body: BodyArgs,
+
+    /// Reply to the comment with this ID.
+    #[clap(long)]
+    pub parent: Option<String>,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+}
+
+impl CliCommand for CmdCommentAdd {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let issue = resolve::issue(&client, &self.issue_id).await?;
+        let body = self.body.read("")?;
+
+        let input = CommentCreateInput {
+            body: Some(body),
+            issue_id: Some(issue.id.into_inner()),
+            parent_id: self.parent,
+        };
+        let payload = client
+            .query(CommentCreate::build(CommentCreateVariables { input }))
+            .await
+            .with_context(|| format!("could not add comment to {}", issue.identifier))?
+            .comment_create;
+        if !payload.success {
+            bail!("comment creation failed");
+        }
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Added comment to {}", issue.identifier);
+                println!("{}", payload.comment.url);
+            }
+            format => println!("{}", render::render_value(&payload.comment, format)?),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::comments::{CommentDelete, CommentIdVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs},
+    util,
+};
+
+/// Delete a comment.
+#[derive(clap::Parser)]
+pub struct CmdCommentDelete {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Delete without asking for confirmation.
+    #[clap(long, short)]
+    pub yes: bool,
+
+    /// Comment ID (see `linear issue comment list`)
+    pub comment_id: String,
+}
+
+impl CliCommand for CmdCommentDelete {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        if !self.yes {
+            if !util::is_interactive() {
+                bail!("refusing to delete the comment without confirmation: pass --yes");
+            }
+            if !util::confirm("Delete this comment?")? {
+                eprintln!("Aborted.");
+                return Ok(());
+            }
+        }
+
+        let payload = self
+            .common
+            .client()?
+            .query(CommentDelete::build(CommentIdVariables {
+                id: self.comment_id.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not delete comment {}", self.comment_id))?
+            .comment_delete;
+        if !payload.success {
+            bail!("comment deletion failed");
+        }
+
+        eprintln!("Deleted comment {}", payload.entity_id);
+        Ok(())
+    }
+}
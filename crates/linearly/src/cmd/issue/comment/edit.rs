@@ -0,0 +1,74 @@
+use anyhow::{bail, Context};
+use cynic::{MutationBuilder, QueryBuilder};
+
+use linear_api::schema::comments::{
+    CommentGet, CommentIdVariables, CommentUpdate, CommentUpdateInput, CommentUpdateVariables,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render,
+};
+
+use super::BodyArgs;
+
+/// Edit a comment.
+///
+/// Without --body or --body-file, the comment is opened in $VISUAL/$EDITOR.
+#[derive(clap::Parser)]
+pub struct CmdCommentEdit {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub body: BodyArgs,
+
+    /// Comment ID (see `linear issue comment list`)
+    pub comment_id: String,
+}
+
+impl CliCommand for CmdCommentEdit {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let comment = client
+            .query(CommentGet::build(CommentIdVariables {
+                id: self.comment_id.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not load comment {}", self.comment_id))?
+            .comment;
+
+        let body = self.body.read(&comment.body)?;
+        if body == comment.body.trim() {
+            eprintln!("No changes.");
+            return Ok(());
+        }
+
+        let payload = client
+            .query(CommentUpdate::build(CommentUpdateVariables {
+                id: comment.id.into_inner(),
+                input: CommentUpdateInput { body: Some(body) },
+            }))
+            .await
+            .context("could not update comment")?
+            .comment_update;
+        if !payload.success {
+            bail!("comment update failed");
+        }
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Updated comment");
+                println!("{}", payload.comment.url);
+            }
+            format => println!("{}", render::render_value(&payload.comment, format)?),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use futures::TryStreamExt;
+
+use linear_api::schema::comments::{Comment, IssueComments, IssueCommentsVariables};
+
+use crate::{
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{markdown, Render},
+};
+
+use super::CommentTableColumns;
+
+/// List the comments of an issue, grouped into threads.
+#[derive(clap::Parser)]
+pub struct CmdCommentList {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<CommentTableColumns>,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+}
+
+impl CliCommand for CmdCommentList {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<Comment>("comment")?;
+        let vars = IssueCommentsVariables {
+            id: self.issue_id.clone(),
+            first: 0,
+            after: None,
+        };
+        let client = self.common.client()?;
+        let comments: Vec<Comment> = client
+            .paginate::<IssueComments>(vars, None)
+            .try_collect()
+            .await?;
+        let comments = super::threaded(comments);
+
+        if let Some(template) = &template {
+            println!("{}", template.render_list(&comments)?);
+        } else {
+            match self.format.resolve() {
+                Format::Table => {
+                    markdown::load_team_keys(&client).await?;
+                    if comments.is_empty() {
+                        eprintln!("No comments on {}.", self.issue_id);
+                    } else {
+                        println!("{}", Comment::render_list_table(&comments, &columns));
+                    }
+                }
+                format => println!("{}", Comment::render_list(&comments, &columns, format)?),
+            }
+        }
+
+        Ok(())
+    }
+}
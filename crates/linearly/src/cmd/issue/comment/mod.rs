@@ -0,0 +1,229 @@
+pub mod add;
+pub mod delete;
+pub mod edit;
+pub mod list;
+pub mod resolve;
+
+use std::path::PathBuf;
+
+use comfy_table::{Attribute, Cell};
+
+use linear_api::schema::comments::Comment;
+
+use crate::{
+    cmd::CliCommand,
+    editor,
+    render::{markdown, Render},
+    util,
+};
+
+/// Manage issue comments.
+#[derive(clap::Subcommand)]
+pub enum CmdIssueComment {
+    #[clap(alias = "ls")]
+    List(list::CmdCommentList),
+    #[clap(alias = "new")]
+    Add(add::CmdCommentAdd),
+    Edit(edit::CmdCommentEdit),
+    #[clap(alias = "rm")]
+    Delete(delete::CmdCommentDelete),
+    Resolve(resolve::CmdCommentResolve),
+    Unresolve(resolve::CmdCommentUnresolve),
+}
+
+impl CliCommand for CmdIssueComment {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        match self {
+            CmdIssueComment::List(c) => c.run().await,
+            CmdIssueComment::Add(c) => c.run().await,
+            CmdIssueComment::Edit(c) => c.run().await,
+            CmdIssueComment::Delete(c) => c.run().await,
+            CmdIssueComment::Resolve(c) => c.run().await,
+            CmdIssueComment::Unresolve(c) => c.run().await,
+        }
+    }
+}
+
+/// Comment body given on the command line.
+#[derive(clap::Args)]
+pub struct BodyArgs {
+    /// Comment body (markdown).
+    #[clap(long, short, conflicts_with = "body_file")]
+    pub body: Option<String>,
+
+    /// Read the comment body from a file.
+    /// Use "-" to read from stdin.
+    #[clap(long)]
+    pub body_file: Option<PathBuf>,
+}
+
+impl BodyArgs {
+    /// Get the body from the flags, or let the user write it in their editor.
+    ///
+    /// An empty body is an error.
+    pub fn read(&self, initial: &str) -> Result<String, anyhow::Error> {
+        let body = match (&self.body, &self.body_file) {
+            (Some(body), _) => body.clone(),
+            (None, Some(path)) => util::read_file_or_stdin(path)?,
+            (None, None) => {
+                if !util::is_interactive() {
+                    anyhow::bail!("no comment body given: use --body or --body-file");
+                }
+                editor::edit(initial, "comment.md")?
+            }
+        };
+
+        let body = body.trim().to_string();
+        if body.is_empty() {
+            anyhow::bail!("aborting due to empty comment body");
+        }
+        Ok(body)
+    }
+}
+
+/// Order comments as threads: top level comments by creation time, each
+/// followed by its replies.
+pub fn threaded(mut comments: Vec<Comment>) -> Vec<Comment> {
+    comments.sort_by(|a, b| a.created_at.0.cmp(&b.created_at.0));
+    let (replies, top): (Vec<_>, Vec<_>) = comments.into_iter().partition(|c| c.parent.is_some());
+
+    let mut replies = replies.into_iter().map(Some).collect::<Vec<_>>();
+    let mut out = Vec::new();
+    for comment in top {
+        let id = comment.id.clone();
+        out.push(comment);
+        for slot in replies.iter_mut() {
+            if slot.as_ref().and_then(|r| r.parent.as_ref()).map(|p| &p.id) == Some(&id) {
+                out.extend(slot.take());
+            }
+        }
+    }
+    // Replies to comments that were not loaded.
+    out.extend(replies.into_iter().flatten());
+    out
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum CommentTableColumns {
+    Id,
+    Author,
+    Created,
+    Updated,
+    Edited,
+    Resolved,
+    Parent,
+    Url,
+    Body,
+}
+
+impl Render for Comment {
+    type Fields = CommentTableColumns;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            CommentTableColumns::Author,
+            CommentTableColumns::Created,
+            CommentTableColumns::Body,
+            CommentTableColumns::Resolved,
+            CommentTableColumns::Id,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            CommentTableColumns::Id,
+            CommentTableColumns::Author,
+            CommentTableColumns::Created,
+            CommentTableColumns::Updated,
+            CommentTableColumns::Resolved,
+            CommentTableColumns::Parent,
+            CommentTableColumns::Body,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        vec![
+            CommentTableColumns::Id,
+            CommentTableColumns::Author,
+            CommentTableColumns::Created,
+            CommentTableColumns::Resolved,
+            CommentTableColumns::Body,
+        ]
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            CommentTableColumns::Id => Cell::new("ID"),
+            CommentTableColumns::Author => Cell::new("Author").add_attribute(Attribute::Bold),
+            CommentTableColumns::Created => Cell::new("Created"),
+            CommentTableColumns::Updated => Cell::new("Updated"),
+            CommentTableColumns::Edited => Cell::new("Edited"),
+            CommentTableColumns::Resolved => Cell::new("Resolved"),
+            CommentTableColumns::Parent => Cell::new("Reply To"),
+            CommentTableColumns::Url => Cell::new("URL"),
+            CommentTableColumns::Body => Cell::new("Comment"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        let timeformat =
+            time::format_description::parse("[year]-[month]-[day] [hour]:[minute]").unwrap();
+        match field {
+            CommentTableColumns::Id => Cell::new(item.id.inner()),
+            CommentTableColumns::Author => {
+                let name = item
+                    .user
+                    .as_ref()
+                    .map(|u| u.display_name.as_str())
+                    .unwrap_or("(unknown)");
+                if item.parent.is_some() {
+                    Cell::new(format!("  ↳ {}", name))
+                } else {
+                    Cell::new(name)
+                }
+            }
+            CommentTableColumns::Created => {
+                let mut v = item
+                    .created_at
+                    .parse()
+                    .unwrap()
+                    .format(&timeformat)
+                    .unwrap();
+                if item.edited_at.is_some() {
+                    v.push_str(" (edited)");
+                }
+                Cell::new(v)
+            }
+            CommentTableColumns::Updated => Cell::new(
+                item.updated_at
+                    .parse()
+                    .unwrap()
+                    .format(&timeformat)
+                    .unwrap(),
+            ),
+            CommentTableColumns::Edited => Cell::new(
+                item.edited_at
+                    .as_ref()
+                    .map(|d| d.parse().unwrap().format(&timeformat).unwrap())
+                    .unwrap_or_default(),
+            ),
+            CommentTableColumns::Parent => Cell::new(
+                item.parent
+                    .as_ref()
+                    .map(|p| p.id.inner())
+                    .unwrap_or_default(),
+            ),
+            CommentTableColumns::Url => Cell::new(&item.url),
+            CommentTableColumns::Resolved => Cell::new(
+                item.resolving_user
+                    .as_ref()
+                    .map(|u| format!("by {}", u.display_name))
+                    .or_else(|| item.resolved_at.as_ref().map(|_| "yes".to_string()))
+                    .unwrap_or_default(),
+            ),
+            CommentTableColumns::Body => Cell::new(markdown::render(&item.body)),
+        }
+    }
+}
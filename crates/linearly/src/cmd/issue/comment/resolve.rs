@@ -0,0 +1,88 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::comments::{Comment, CommentIdVariables, CommentResolve, CommentUnresolve};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render,
+};
+
+/// Mark a comment thread as resolved.
+#[derive(clap::Parser)]
+pub struct CmdCommentResolve {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Comment ID (see `linear issue comment list`)
+    pub comment_id: String,
+}
+
+impl CliCommand for CmdCommentResolve {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let payload = self
+            .common
+            .client()?
+            .query(CommentResolve::build(CommentIdVariables {
+                id: self.comment_id.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not resolve comment {}", self.comment_id))?
+            .comment_resolve;
+        if !payload.success {
+            bail!("resolving the comment failed");
+        }
+
+        print_result(self.format.resolve(), "Resolved", &payload.comment)
+    }
+}
+
+/// Mark a resolved comment thread as unresolved.
+#[derive(clap::Parser)]
+pub struct CmdCommentUnresolve {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Comment ID (see `linear issue comment list`)
+    pub comment_id: String,
+}
+
+impl CliCommand for CmdCommentUnresolve {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let payload = self
+            .common
+            .client()?
+            .query(CommentUnresolve::build(CommentIdVariables {
+                id: self.comment_id.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not unresolve comment {}", self.comment_id))?
+            .comment_unresolve;
+        if !payload.success {
+            bail!("unresolving the comment failed");
+        }
+
+        print_result(self.format.resolve(), "Unresolved", &payload.comment)
+    }
+}
+
+fn print_result(format: Format, action: &str, comment: &Comment) -> Result<(), anyhow::Error> {
+    match format {
+        Format::Table => {
+            eprintln!("{} comment", action);
+            println!("{}", comment.url);
+        }
+        format => println!("{}", render::render_value(comment, format)?),
+    }
+    Ok(())
+}
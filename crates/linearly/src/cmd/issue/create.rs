@@ -0,0 +1,277 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::issue_create::{IssueCreate, IssueCreateInput, IssueCreateVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    editor::{self, IssueDraft},
+    render, resolve, util,
+};
+
+use super::IssuePriority;
+
+/// Create a new issue.
+///
+/// When running in a terminal without --description, the issue is written in
+/// $VISUAL/$EDITOR as markdown with a front matter header.
+/// Otherwise missing required values (team and title) are prompted for.
+#[derive(clap::Parser)]
+pub struct CmdIssueCreate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Team of the issue.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+
+    /// Issue title.
+    #[clap(long)]
+    pub title: Option<String>,
+
+    /// Issue description (markdown).
+    #[clap(long, short, conflicts_with = "description_file")]
+    pub description: Option<String>,
+
+    /// Read the description from a file.
+    /// Use "-" to read from stdin.
+    #[clap(long)]
+    pub description_file: Option<PathBuf>,
+
+    /// Name of the assigned user.
+    ///
+    /// Use "me" as an alias for yourself.
+    #[clap(long, short)]
+    pub assignee: Option<String>,
+
+    /// Label name. Can be specified multiple times.
+    #[clap(long = "label", short)]
+    pub labels: Vec<String>,
+
+    /// Priority: none, urgent, high, medium, low (or 0-4).
+    #[clap(long, short)]
+    pub priority: Option<IssuePriority>,
+
+    /// Estimate in points.
+    #[clap(long, short)]
+    pub estimate: Option<i32>,
+
+    /// Project name or slug ID.
+    #[clap(long)]
+    pub project: Option<String>,
+
+    /// Cycle number, or "current", "next" or "previous".
+    #[clap(long)]
+    pub cycle: Option<String>,
+
+    /// Parent issue ID (eg: MYTEAM-123).
+    #[clap(long)]
+    pub parent: Option<String>,
+
+    /// Name of the workflow state (eg: "In Progress").
+    #[clap(long, short)]
+    pub state: Option<String>,
+
+    /// Never prompt for missing values or open an editor.
+    #[clap(long)]
+    pub no_interactive: bool,
+}
+
+impl CliCommand for CmdIssueCreate {
+    type Output = ();
+
+    async fn run(mut self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        let interactive = !self.no_interactive && util::is_interactive();
+        let has_description = self.description.is_some() || self.description_file.is_some();
+
+        if interactive && !has_description {
+            self.edit_draft(&client).await?;
+        } else if interactive && (self.team.is_none() || self.title.is_none()) {
+            self.prompt_missing(&client).await?;
+        }
+
+        let Some(team) = &self.team else {
+            bail!("missing --team");
+        };
+        let Some(title) = self.title.clone() else {
+            bail!("missing --title");
+        };
+
+        let team = resolve::team(&client, team).await?;
+
+        let description = match (&self.description, &self.description_file) {
+            (Some(d), _) => Some(d.clone()),
+            (None, Some(path)) => Some(util::read_file_or_stdin(path)?),
+            (None, None) => None,
+        };
+
+        let assignee_id = match &self.assignee {
+            Some(name) => Some(resolve::user(&client, name).await?.id.into_inner()),
+            None => None,
+        };
+
+        let label_ids = if self.labels.is_empty() {
+            None
+        } else {
+            let labels = resolve::labels(&client, Some(&team), &self.labels).await?;
+            Some(labels.into_iter().map(|l| l.id.into_inner()).collect())
+        };
+
+        let project_id = match &self.project {
+            Some(name) => Some(resolve::project(&client, name).await?.id.into_inner()),
+            None => None,
+        };
+
+        let cycle_id = match &self.cycle {
+            Some(spec) => Some(resolve::cycle(&client, &team, spec).await?.id.into_inner()),
+            None => None,
+        };
+
+        let parent_id = match &self.parent {
+            Some(id) => Some(resolve::issue(&client, id).await?.id.into_inner()),
+            None => None,
+        };
+
+        let state_id = match &self.state {
+            Some(name) => Some(
+                resolve::workflow_state(&client, &team, name)
+                    .await?
+                    .id
+                    .into_inner(),
+            ),
+            None => None,
+        };
+
+        let input = IssueCreateInput {
+            team_id: team.id.into_inner(),
+            title: Some(title),
+            description,
+            assignee_id,
+            parent_id,
+            priority: self.priority.map(IssuePriority::value),
+            estimate: self.estimate,
+            label_ids,
+            cycle_id,
+            project_id,
+            state_id,
+        };
+
+        let payload = client
+            .query(IssueCreate::build(IssueCreateVariables { input }))
+            .await
+            .context("could not create issue")?
+            .issue_create;
+
+        let issue = match payload.issue {
+            Some(issue) if payload.success => issue,
+            _ => bail!("issue creation failed"),
+        };
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Created issue {}: {}", issue.identifier, issue.title);
+                println!("{}", issue.url);
+            }
+            format => println!("{}", render::render_value(&issue, format)?),
+        }
+
+        Ok(())
+    }
+}
+
+impl CmdIssueCreate {
+    /// Let the user write the issue in their editor.
+    async fn edit_draft(&mut self, client: &linear_api::Client) -> Result<(), anyhow::Error> {
+        let mut hints = vec![
+            "Lines starting with '#' in this header are ignored.".to_string(),
+            "An empty title aborts the issue creation.".to_string(),
+            "Priority: none, urgent, high, medium or low.".to_string(),
+        ];
+        if self.team.is_none() {
+            let keys = resolve::teams(client)
+                .await?
+                .into_iter()
+                .map(|t| t.key)
+                .collect::<Vec<_>>()
+                .join(", ");
+            hints.push(format!("Teams: {}", keys));
+        }
+
+        let draft = IssueDraft {
+            title: self.title.clone().unwrap_or_default(),
+            team: self.team.clone().unwrap_or_default(),
+            assignee: self.assignee.clone().unwrap_or_default(),
+            labels: self.labels.clone(),
+            priority: self.priority.map(|p| p.to_string()).unwrap_or_default(),
+            description: String::new(),
+        };
+
+        let content = editor::edit(&draft.to_markdown(&hints), "issue.md")?;
+        let draft = IssueDraft::parse(&content)?;
+
+        if draft.title.is_empty() {
+            bail!("aborting issue creation due to empty title");
+        }
+
+        self.title = Some(draft.title);
+        self.team = Some(draft.team).filter(|t| !t.is_empty());
+        self.assignee = Some(draft.assignee).filter(|a| !a.is_empty());
+        self.labels = draft.labels;
+        self.priority = if draft.priority.is_empty() {
+            None
+        } else {
+            Some(draft.priority.parse().map_err(anyhow::Error::msg)?)
+        };
+        self.description = Some(draft.description).filter(|d| !d.is_empty());
+
+        Ok(())
+    }
+
+    async fn prompt_missing(&mut self, client: &linear_api::Client) -> Result<(), anyhow::Error> {
+        if self.team.is_none() {
+            let teams = resolve::teams(client).await?;
+            let keys = teams
+                .iter()
+                .map(|t| format!("{} ({})", t.key, t.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            eprintln!("Available teams: {}", keys);
+            self.team = Some(util::prompt_required("Team")?);
+        }
+
+        if self.title.is_none() {
+            self.title = Some(util::prompt_required("Title")?);
+        }
+
+        if self.assignee.is_none() {
+            self.assignee = util::prompt_optional("Assignee (name or 'me')")?;
+        }
+
+        if self.priority.is_none() {
+            self.priority = util::prompt_optional("Priority (urgent, high, medium, low)")?
+                .map(|p| p.parse())
+                .transpose()
+                .map_err(anyhow::Error::msg)?;
+        }
+
+        if self.labels.is_empty() {
+            if let Some(labels) = util::prompt_optional("Labels (comma separated)")? {
+                self.labels = labels
+                    .split(',')
+                    .map(|l| l.trim().to_string())
+                    .filter(|l| !l.is_empty())
+                    .collect();
+            }
+        }
+
+        Ok(())
+    }
+}
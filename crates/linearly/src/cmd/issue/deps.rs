@@ -0,0 +1,321 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use anyhow::{bail, Context};
+use colored::Colorize;
+use cynic::QueryBuilder;
+
+use linear_api::{
+    schema::relations::{IssueRelationsGet, IssueRelationsVariables, RelatedIssue},
+    Client,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render,
+};
+
+/// Output format of the dependency graph.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum DepsFormat {
+    /// ASCII tree.
+    Tree,
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+    Json,
+}
+
+/// Show the blocking graph of an issue.
+///
+/// Walks "blocked by" relations recursively and prints the issues that have
+/// to be done before the given issue. With --reverse, the issues blocked by
+/// the given issue are shown instead.
+#[derive(clap::Parser)]
+pub struct CmdIssueDeps {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, value_enum, default_value = "tree")]
+    pub format: DepsFormat,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    /// Show the issues blocked by the issue instead of its blockers.
+    #[clap(long, short)]
+    pub reverse: bool,
+
+    /// Maximum depth to follow relations.
+    #[clap(long, default_value = "10")]
+    pub depth: usize,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+}
+
+/// The blocking graph: issues by identifier, and edges from each issue to
+/// the issues found next to it.
+#[derive(serde::Serialize)]
+struct Graph {
+    root: String,
+    issues: BTreeMap<String, RelatedIssue>,
+    edges: BTreeMap<String, Vec<String>>,
+}
+
+impl CliCommand for CmdIssueDeps {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+        let graph = walk(&client, &self.issue_id, self.reverse, self.depth).await?;
+
+        if let Some(template) = &template {
+            println!("{}", template.render(&graph)?);
+            return Ok(());
+        }
+
+        // Like tables, the tree is printed as JSON with --query.
+        let query = render::query::is_active();
+        let output = match self.format {
+            DepsFormat::Tree if !query => render_tree(&graph, self.reverse),
+            DepsFormat::Dot | DepsFormat::Mermaid if query => {
+                bail!("--query can not be used with --format dot or mermaid")
+            }
+            DepsFormat::Dot => render_dot(&graph, self.reverse),
+            DepsFormat::Mermaid => render_mermaid(&graph, self.reverse),
+            DepsFormat::Tree | DepsFormat::Json => {
+                render::render_value(&graph, Format::Json)? + "\n"
+            }
+        };
+        print!("{}", output);
+
+        Ok(())
+    }
+}
+
+/// Load the graph breadth first, one issue at a time.
+async fn walk(
+    client: &Client,
+    issue_id: &str,
+    reverse: bool,
+    max_depth: usize,
+) -> Result<Graph, anyhow::Error> {
+    let mut issues = BTreeMap::new();
+    let mut edges = BTreeMap::<String, Vec<String>>::new();
+    let mut queue = vec![(issue_id.to_string(), 0)];
+    let mut root = None;
+
+    while !queue.is_empty() {
+        let (id, depth) = queue.remove(0);
+        if edges.contains_key(&id) {
+            continue;
+        }
+        let issue = client
+            .query(IssueRelationsGet::build(IssueRelationsVariables {
+                id: id.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not load issue {}", id))?
+            .issue;
+        let identifier = issue.identifier.clone();
+        root.get_or_insert_with(|| identifier.clone());
+
+        // "blocks" relations point from the blocking to the blocked issue.
+        let next = if reverse {
+            issue
+                .relations
+                .nodes
+                .into_iter()
+                .filter(|r| r.type_ == "blocks")
+                .map(|r| r.related_issue)
+                .collect::<Vec<_>>()
+        } else {
+            issue
+                .inverse_relations
+                .nodes
+                .into_iter()
+                .filter(|r| r.type_ == "blocks")
+                .map(|r| r.issue)
+                .collect::<Vec<_>>()
+        };
+
+        let mut targets = Vec::new();
+        for other in next {
+            targets.push(other.identifier.clone());
+            if depth + 1 < max_depth && !edges.contains_key(&other.identifier) {
+                queue.push((other.identifier.clone(), depth + 1));
+            }
+            issues.entry(other.identifier.clone()).or_insert(other);
+        }
+        targets.sort();
+        targets.dedup();
+        edges.insert(identifier.clone(), targets);
+        issues.insert(
+            identifier,
+            RelatedIssue {
+                id: issue.id,
+                identifier: issue.identifier,
+                title: issue.title,
+                state: issue.state,
+            },
+        );
+    }
+
+    Ok(Graph {
+        root: root.unwrap_or_else(|| issue_id.to_string()),
+        issues,
+        edges,
+    })
+}
+
+fn is_done(issue: &RelatedIssue) -> bool {
+    matches!(issue.state.type_.as_str(), "completed" | "canceled")
+}
+
+fn issue_line(issue: &RelatedIssue) -> String {
+    let line = format!(
+        "{} {} {}",
+        issue.identifier.bold(),
+        issue.title,
+        format!("[{}]", issue.state.name).dimmed()
+    );
+    if is_done(issue) {
+        line.dimmed().to_string()
+    } else {
+        line
+    }
+}
+
+fn render_tree(graph: &Graph, reverse: bool) -> String {
+    let mut out = String::new();
+    let Some(root) = graph.issues.get(&graph.root) else {
+        return out;
+    };
+    writeln!(out, "{}", issue_line(root)).unwrap();
+
+    let mut path = vec![graph.root.clone()];
+    render_children(graph, &graph.root, "", &mut path, &mut out);
+
+    if graph.edges.get(&graph.root).is_none_or(|e| e.is_empty()) {
+        let message = if reverse {
+            "Not blocking any issues."
+        } else {
+            "Not blocked by any issues."
+        };
+        writeln!(out, "{}", message.dimmed()).unwrap();
+    }
+    out
+}
+
+fn render_children(
+    graph: &Graph,
+    identifier: &str,
+    indent: &str,
+    path: &mut Vec<String>,
+    out: &mut String,
+) {
+    let Some(children) = graph.edges.get(identifier) else {
+        return;
+    };
+    for (index, child) in children.iter().enumerate() {
+        let last = index + 1 == children.len();
+        let (branch, next_indent) = if last {
+            ("└── ", format!("{}    ", indent))
+        } else {
+            ("├── ", format!("{}│   ", indent))
+        };
+        let line = match graph.issues.get(child) {
+            Some(issue) => issue_line(issue),
+            None => child.clone(),
+        };
+        if path.contains(child) {
+            writeln!(out, "{}{}{} {}", indent, branch, line, "(cycle)".red()).unwrap();
+            continue;
+        }
+        writeln!(out, "{}{}{}", indent, branch, line).unwrap();
+        path.push(child.clone());
+        render_children(graph, child, &next_indent, path, out);
+        path.pop();
+    }
+}
+
+/// Sorted edges as (blocking, blocked) pairs.
+fn blocking_edges(graph: &Graph, reverse: bool) -> Vec<(&str, &str)> {
+    let mut edges = graph
+        .edges
+        .iter()
+        .flat_map(|(from, targets)| targets.iter().map(move |to| (from.as_str(), to.as_str())))
+        .map(|(from, to)| if reverse { (from, to) } else { (to, from) })
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+fn sorted_issues(graph: &Graph) -> Vec<&RelatedIssue> {
+    let mut issues = graph.issues.values().collect::<Vec<_>>();
+    issues.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+    issues
+}
+
+fn render_dot(graph: &Graph, reverse: bool) -> String {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut out = String::new();
+    writeln!(out, "digraph deps {{").unwrap();
+    writeln!(out, "  rankdir=LR;").unwrap();
+    writeln!(out, "  node [shape=box];").unwrap();
+    for issue in sorted_issues(graph) {
+        let mut attrs = format!(
+            "label=\"{}\\n{}\\n({})\"",
+            escape(&issue.identifier),
+            escape(&issue.title),
+            escape(&issue.state.name)
+        );
+        if issue.identifier == graph.root {
+            attrs.push_str(", penwidth=2");
+        }
+        if is_done(issue) {
+            attrs.push_str(", style=dashed");
+        }
+        writeln!(out, "  \"{}\" [{}];", escape(&issue.identifier), attrs).unwrap();
+    }
+    for (from, to) in blocking_edges(graph, reverse) {
+        writeln!(out, "  \"{}\" -> \"{}\";", escape(from), escape(to)).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn render_mermaid(graph: &Graph, reverse: bool) -> String {
+    let node_id = |identifier: &str| identifier.replace(|c: char| !c.is_alphanumeric(), "_");
+    let escape = |s: &str| s.replace('"', "#quot;");
+
+    let mut out = String::new();
+    writeln!(out, "flowchart LR").unwrap();
+    for issue in sorted_issues(graph) {
+        writeln!(
+            out,
+            "  {}[\"{}: {}<br/>({})\"]",
+            node_id(&issue.identifier),
+            escape(&issue.identifier),
+            escape(&issue.title),
+            escape(&issue.state.name)
+        )
+        .unwrap();
+    }
+    for (from, to) in blocking_edges(graph, reverse) {
+        writeln!(out, "  {} -->|blocks| {}", node_id(from), node_id(to)).unwrap();
+    }
+    let done = sorted_issues(graph)
+        .into_iter()
+        .filter(|i| is_done(i))
+        .map(|i| node_id(&i.identifier))
+        .collect::<Vec<_>>();
+    if !done.is_empty() {
+        writeln!(out, "  classDef done stroke-dasharray: 5 5;").unwrap();
+        writeln!(out, "  class {} done;", done.join(",")).unwrap();
+    }
+    out
+}
@@ -0,0 +1,215 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::{
+    schema::{
+        issue_list::Issue,
+        issue_update::{IssueUpdate, IssueUpdateInput, IssueUpdateVariables},
+        Nullable,
+    },
+    Client,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    editor::{self, IssueDraft},
+    render, resolve, util,
+};
+
+use super::IssuePriority;
+
+/// Edit the title and description of an issue.
+///
+/// Without --title or --description, the issue is opened in $VISUAL/$EDITOR
+/// as markdown with a front matter header (title, team, assignee, labels and
+/// priority), and all changed fields are saved.
+#[derive(clap::Parser)]
+pub struct CmdIssueEdit {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// New issue title.
+    #[clap(long)]
+    pub title: Option<String>,
+
+    /// New issue description (markdown).
+    #[clap(long, short, conflicts_with = "description_file")]
+    pub description: Option<String>,
+
+    /// Read the new description from a file.
+    /// Use "-" to read from stdin.
+    #[clap(long)]
+    pub description_file: Option<PathBuf>,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+}
+
+impl CliCommand for CmdIssueEdit {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let issue = resolve::issue(&client, &self.issue_id).await?;
+
+        let description = match (&self.description, &self.description_file) {
+            (Some(d), _) => Some(d.clone()),
+            (None, Some(path)) => Some(util::read_file_or_stdin(path)?),
+            (None, None) => None,
+        };
+
+        let input = if self.title.is_some() || description.is_some() {
+            IssueUpdateInput {
+                title: self.title,
+                description,
+                ..Default::default()
+            }
+        } else {
+            if !util::is_interactive() {
+                bail!("no changes given: use --title or --description, or run in a terminal to open an editor");
+            }
+            match edit_in_editor(&client, &issue).await? {
+                Some(input) => input,
+                None => {
+                    eprintln!("No changes.");
+                    return Ok(());
+                }
+            }
+        };
+
+        let payload = client
+            .query(IssueUpdate::build(IssueUpdateVariables {
+                id: issue.id.into_inner(),
+                input,
+            }))
+            .await
+            .with_context(|| format!("could not update issue {}", issue.identifier))?
+            .issue_update;
+
+        let issue = match payload.issue {
+            Some(issue) if payload.success => issue,
+            _ => bail!("issue update failed"),
+        };
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Updated issue {}: {}", issue.identifier, issue.title);
+                println!("{}", issue.url);
+            }
+            format => println!("{}", render::render_value(&issue, format)?),
+        }
+
+        Ok(())
+    }
+}
+
+fn draft_from_issue(issue: &Issue) -> IssueDraft {
+    IssueDraft {
+        title: issue.title.clone(),
+        team: issue.team.key.clone(),
+        assignee: issue
+            .assignee
+            .as_ref()
+            .map(|a| a.display_name.clone())
+            .unwrap_or_default(),
+        labels: issue.labels.nodes.iter().map(|l| l.name.clone()).collect(),
+        priority: IssuePriority::from_value(issue.priority).to_string(),
+        description: issue.description.clone().unwrap_or_default(),
+    }
+}
+
+/// Open the issue in the editor and build an update input from the changed
+/// fields.
+///
+/// Returns `None` if nothing was changed.
+async fn edit_in_editor(
+    client: &Client,
+    issue: &Issue,
+) -> Result<Option<IssueUpdateInput>, anyhow::Error> {
+    let original = draft_from_issue(issue);
+    let hints = vec![
+        "Lines starting with '#' in this header are ignored.".to_string(),
+        "Leave the assignee empty to unassign the issue.".to_string(),
+        "Priority: none, urgent, high, medium or low.".to_string(),
+    ];
+    let content = editor::edit(&original.to_markdown(&hints), "issue.md")?;
+    let draft = IssueDraft::parse(&content)?;
+
+    if draft.title.is_empty() {
+        bail!("aborting: the title must not be empty");
+    }
+
+    let mut input = IssueUpdateInput::default();
+    let mut changed = false;
+
+    if draft.title != original.title {
+        input.title = Some(draft.title.clone());
+        changed = true;
+    }
+    if draft.description != original.description.trim() {
+        input.description = Some(draft.description.clone());
+        changed = true;
+    }
+
+    let team_changed = !draft.team.eq_ignore_ascii_case(&original.team);
+    let team = if team_changed {
+        let team = resolve::team(client, &draft.team).await?;
+        input.team_id = Some(team.id.clone().into_inner());
+        changed = true;
+        team
+    } else {
+        issue.team.clone()
+    };
+
+    if draft.assignee != original.assignee {
+        input.assignee_id = Some(if draft.assignee.is_empty() {
+            Nullable::Null
+        } else {
+            Nullable::Value(
+                resolve::user(client, &draft.assignee)
+                    .await?
+                    .id
+                    .into_inner(),
+            )
+        });
+        changed = true;
+    }
+
+    let labels_changed = {
+        let mut a = draft
+            .labels
+            .iter()
+            .map(|l| l.to_lowercase())
+            .collect::<Vec<_>>();
+        let mut b = original
+            .labels
+            .iter()
+            .map(|l| l.to_lowercase())
+            .collect::<Vec<_>>();
+        a.sort();
+        b.sort();
+        a != b
+    };
+    if labels_changed || team_changed {
+        let labels = resolve::labels(client, Some(&team), &draft.labels).await?;
+        input.label_ids = Some(labels.into_iter().map(|l| l.id.into_inner()).collect());
+        changed |= labels_changed;
+    }
+
+    let priority: IssuePriority = if draft.priority.is_empty() {
+        IssuePriority::None
+    } else {
+        draft.priority.parse().map_err(anyhow::Error::msg)?
+    };
+    if priority != IssuePriority::from_value(issue.priority) {
+        input.priority = Some(priority.value());
+        changed = true;
+    }
+
+    Ok(changed.then_some(input))
+}
@@ -0,0 +1,103 @@
+use linear_api::{
+    schema::issue_list::{IssueFilter, UserFilter},
+    Client,
+};
+
+use crate::resolve;
+
+use super::query;
+
+/// Criteria for selecting issues, shared by all commands that operate on
+/// multiple issues.
+#[derive(Clone, Debug, Default)]
+pub struct IssueSelection {
+    /// Name of the assigned user, or "me".
+    pub assignee: Option<String>,
+    /// Name of the creator, or "me".
+    pub author: Option<String>,
+    /// Short team KEY.
+    pub team: Option<String>,
+    /// Label names; issues must have all of them.
+    pub labels: Vec<String>,
+    /// Name of a subscribed user, or "me".
+    pub subscriber: Option<String>,
+    /// Name of a user who commented, or "me".
+    pub commented_by: Option<String>,
+    /// Name of a user mentioned in the description or comments, or "me".
+    pub mentioned: Option<String>,
+    /// Only issues without assignee.
+    pub unassigned: bool,
+    /// Only issues without project.
+    pub no_project: bool,
+    /// Full text search.
+    pub search: Option<String>,
+    /// Filter queries (see [`query`]); issues must match all of them.
+    pub queries: Vec<String>,
+}
+
+impl IssueSelection {
+    pub async fn to_filter(&self, client: &Client) -> Result<Option<IssueFilter>, anyhow::Error> {
+        let mut filters = Vec::<IssueFilter>::new();
+
+        if let Some(name) = self.assignee.clone() {
+            let filter = if name == "me" {
+                IssueFilter::assignee_me()
+            } else {
+                IssueFilter::assignee_username(name)
+            };
+            filters.push(filter);
+        }
+        if let Some(name) = self.author.clone() {
+            let filter = if name == "me" {
+                IssueFilter::creator_me()
+            } else {
+                IssueFilter::creator_username(name)
+            };
+            filters.push(filter);
+        }
+        if let Some(name) = self.subscriber.clone() {
+            filters.push(IssueFilter::subscriber(user_filter(name)));
+        }
+        if let Some(name) = self.commented_by.clone() {
+            filters.push(IssueFilter::commenter(user_filter(name)));
+        }
+        if let Some(name) = &self.mentioned {
+            // Mentions link to the profile URL, so the user has to be known.
+            let user = resolve::user(client, name).await?;
+            filters.push(IssueFilter::mentioning(user.url));
+        }
+        if self.unassigned {
+            filters.push(IssueFilter::unassigned());
+        }
+        if self.no_project {
+            filters.push(IssueFilter::without_project());
+        }
+        if let Some(team) = self.team.clone() {
+            filters.push(IssueFilter::team_key(team));
+        }
+        for label in &self.labels {
+            filters.push(IssueFilter::label(label.clone()));
+        }
+        if let Some(search) = self.search.clone() {
+            filters.push(IssueFilter::search(search));
+        }
+        for query in &self.queries {
+            let labels = resolve::group_label_ids(client, &query::group_labels(query)).await?;
+            filters.push(query::parse(query, &labels)?);
+        }
+
+        Ok(match filters.len() {
+            0 => None,
+            1 => Some(filters.pop().unwrap()),
+            _ => Some(IssueFilter::new_and(filters)),
+        })
+    }
+}
+
+fn user_filter(name: String) -> UserFilter {
+    if name == "me" {
+        UserFilter::me()
+    } else {
+        UserFilter::username(name)
+    }
+}
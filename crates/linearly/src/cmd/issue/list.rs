@@ -1,14 +1,19 @@
-use anyhow::Context;
-use cynic::QueryBuilder;
+use futures::TryStreamExt;
 
 use linear_api::schema::{
-    issue_list::{Issue, IssueFilter, IssueList, IssueListVariables},
+    issue_list::{Issue, IssueList, IssueListVariables},
     PaginationOrderBy,
 };
 
 use crate::{
-    cmd::{CliCommand, CommonArgs, Format},
-    render::Render,
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{self, markdown, Render},
+};
+
+use super::{
+    filter::IssueSelection,
+    sort::{self, IssueSortKey, SortNulls},
+    tree, IssueTableColumns,
 };
 
 #[derive(clap::Parser)]
@@ -16,93 +21,170 @@ pub struct CmdIssueList {
     #[clap(flatten)]
     common: CommonArgs,
 
+    /// Maximum number of issues to return.
     #[clap(long, default_value = "20")]
-    pub limit: i32,
+    pub limit: usize,
+
+    /// Return all matching issues, ignoring --limit.
+    #[clap(long)]
+    pub all: bool,
 
     /// Name of the assigned user.
     ///
     /// Use "me" as an alias for yourself.
-    #[clap(long)]
+    #[clap(long, conflicts_with = "unassigned")]
     pub assignee: Option<String>,
 
+    /// Only show issues without assignee.
+    #[clap(long)]
+    pub unassigned: bool,
+
     /// Name of the creator.
     ///
     /// Use "me" as an alias for yourself.
     #[clap(long)]
     pub author: Option<String>,
 
+    /// Name of a user subscribed to the issue.
+    ///
+    /// Use "me" as an alias for yourself.
+    #[clap(long)]
+    pub subscriber: Option<String>,
+
+    /// Name of a user who commented on the issue.
+    ///
+    /// Use "me" as an alias for yourself.
+    #[clap(long)]
+    pub commented_by: Option<String>,
+
+    /// Name of a user mentioned in the description or comments.
+    ///
+    /// Use "me" as an alias for yourself.
+    #[clap(long)]
+    pub mentioned: Option<String>,
+
     /// Filter by team.
     /// Expects the short team KEY.
     #[clap(long, short)]
     pub team: Option<String>,
 
+    /// Only show issues with this label. Can be specified multiple times.
+    ///
+    /// Labels inside a group can be given as "Group/Label".
+    #[clap(long = "label", short)]
+    pub labels: Vec<String>,
+
+    /// Only show issues without project.
+    #[clap(long)]
+    pub no_project: bool,
+
     /// Search for the given string.
     #[clap(long, short)]
     pub search: Option<String>,
 
+    /// Filter query, eg: 'state:started label:bug priority:>=2 -assignee:none'.
+    ///
+    /// Terms have the form `key:value` and are combined with AND, unless
+    /// separated by OR. `NOT` or a leading "-" negates a term or a
+    /// parenthesized group. Comma separated values match any of them, and
+    /// words without a key search the issue content.
+    ///
+    /// Keys: assignee, creator, team, state, label, priority, estimate,
+    /// project, cycle, parent, title, search, created, updated, started,
+    /// completed, canceled, due.
+    ///
+    /// "none" matches issues without a value (eg: assignee:none), "me" is
+    /// an alias for yourself. priority, estimate, cycle and the date keys
+    /// support the comparisons >, >=, < and <=. Dates are YYYY-MM-DD, "today"
+    /// or relative like -7d or +2w (units: h, d, w, m, y); without an
+    /// operator, created, updated, started, completed and canceled match on
+    /// or after the date.
+    ///
+    /// Can be specified multiple times.
+    #[clap(long = "filter")]
+    pub filters: Vec<String>,
+
+    /// Comma separated fields to sort by, eg: "priority,-updated".
+    ///
+    /// A leading "-" sorts in descending order. Fields: priority, estimate,
+    /// title, label, sla, created, updated, completed, due, cycle, milestone,
+    /// assignee, project, team, manual, state.
+    /// Defaults to the most recently updated issues first.
+    #[clap(long, value_delimiter = ',', allow_hyphen_values = true)]
+    pub sort: Vec<IssueSortKey>,
+
+    /// Where to put issues without a value for the sort fields.
+    #[clap(long, requires = "sort")]
+    pub nulls: Option<SortNulls>,
+
+    /// Group issues below their parent issue.
+    #[clap(long)]
+    pub tree: bool,
+
     #[clap(long, short, default_value = "table")]
     pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<IssueTableColumns>,
 }
 
 impl CliCommand for CmdIssueList {
     type Output = ();
 
     async fn run(self) -> Result<(), anyhow::Error> {
-        let filter = {
-            let mut filters = Vec::<IssueFilter>::new();
-
-            if let Some(name) = self.assignee {
-                let filter = if name == "me" {
-                    IssueFilter::assignee_me()
-                } else {
-                    IssueFilter::assignee_username(name)
-                };
-                filters.push(filter);
-            }
-            if let Some(name) = self.author {
-                let filter = if name == "me" {
-                    IssueFilter::assignee_me()
-                } else {
-                    IssueFilter::assignee_username(name)
-                };
-                filters.push(filter);
-            }
-            if let Some(team) = self.team {
-                filters.push(IssueFilter::team_key(team));
-            }
-            if let Some(search) = self.search {
-                filters.push(IssueFilter::search(search));
-            }
-
-            match filters.len() {
-                0 => None,
-                1 => Some(filters.pop().unwrap()),
-                _ => Some(IssueFilter::new_and(filters)),
-            }
-        };
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+        let columns = self.columns.resolve::<Issue>("issue")?;
+        let filter = IssueSelection {
+            assignee: self.assignee,
+            author: self.author,
+            team: self.team,
+            labels: self.labels,
+            subscriber: self.subscriber,
+            commented_by: self.commented_by,
+            mentioned: self.mentioned,
+            unassigned: self.unassigned,
+            no_project: self.no_project,
+            search: self.search,
+            queries: self.filters,
+        }
+        .to_filter(&client)
+        .await?;
 
         let vars = IssueListVariables {
-            first: self.limit,
+            first: 0,
+            after: None,
             order: PaginationOrderBy::UpdatedAt,
-            sort: None,
+            sort: sort::sort_inputs(&self.sort, self.nulls),
             filter,
         };
-        let op = IssueList::build(vars);
-
-        let res = self
-            .common
-            .client()?
-            .run(op)
-            .await?
-            .data
-            .context("no data")?;
-
-        let output = match self.format {
-            Format::Table => {
-                let columns = Issue::default_list_fields();
-                Issue::render_list_table(&res.issues.nodes, &columns)
+        let limit = (!self.all).then_some(self.limit);
+
+        let issues = client.paginate::<IssueList>(vars, limit);
+        if let Some(template) = &template {
+            return template.print_stream(issues).await;
+        }
+        if let Format::Ndjson = self.format {
+            return render::stream_ndjson(issues).await;
+        }
+        let issues: Vec<Issue> = issues.try_collect().await?;
+
+        let format = self.format.resolve();
+        if matches!(format, Format::Table)
+            && columns
+                .iter()
+                .any(|c| matches!(c, IssueTableColumns::Description))
+        {
+            markdown::load_team_keys(&client).await?;
+        }
+        let output = match format {
+            Format::Table if self.tree => {
+                tree::render_issue_list_tree(&issues).trim_end().to_string()
             }
-            Format::Json => serde_json::to_string_pretty(&res.issues.nodes)?,
+            format => Issue::render_list(&issues, &columns, format)?,
         };
 
         println!("{}", output);
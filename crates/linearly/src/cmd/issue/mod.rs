@@ -1,12 +1,25 @@
 pub mod checkout;
+pub mod comment;
+pub mod create;
+pub mod deps;
+pub mod edit;
+pub mod filter;
 pub mod list;
+pub mod query;
+pub mod relate;
+pub mod reparent;
+pub mod sort;
+pub mod tree;
+pub mod update;
 pub mod view;
 
 use comfy_table::{Attribute, Cell};
 
-use crate::render::Render;
+use linear_api::schema::DateTime;
 
-use super::CliCommand;
+use crate::render::{format_date, markdown, Render};
+
+use super::{cycle::cycle_title, CliCommand};
 
 #[derive(clap::Subcommand)]
 pub enum CmdIssue {
@@ -15,6 +28,17 @@ pub enum CmdIssue {
     View(view::CmdIssueView),
     #[clap(alias = "co")]
     Checkout(checkout::CmdIssueCheckout),
+    #[clap(alias = "new")]
+    Create(create::CmdIssueCreate),
+    Edit(edit::CmdIssueEdit),
+    Update(update::CmdIssueUpdate),
+    Relate(relate::CmdIssueRelate),
+    Unrelate(relate::CmdIssueUnrelate),
+    Deps(deps::CmdIssueDeps),
+    Tree(tree::CmdIssueTree),
+    Reparent(reparent::CmdIssueReparent),
+    #[clap(subcommand)]
+    Comment(comment::CmdIssueComment),
 }
 
 impl CliCommand for CmdIssue {
@@ -25,20 +49,109 @@ impl CliCommand for CmdIssue {
             CmdIssue::List(c) => c.run().await,
             CmdIssue::View(c) => c.run().await,
             CmdIssue::Checkout(c) => c.run().await,
+            CmdIssue::Create(c) => c.run().await,
+            CmdIssue::Edit(c) => c.run().await,
+            CmdIssue::Update(c) => c.run().await,
+            CmdIssue::Relate(c) => c.run().await,
+            CmdIssue::Unrelate(c) => c.run().await,
+            CmdIssue::Deps(c) => c.run().await,
+            CmdIssue::Tree(c) => c.run().await,
+            CmdIssue::Reparent(c) => c.run().await,
+            CmdIssue::Comment(c) => c.run().await,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Issue priority.
+///
+/// Parsed from either the name or the numeric value used by Linear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IssuePriority {
+    None = 0,
+    Urgent = 1,
+    High = 2,
+    Medium = 3,
+    Low = 4,
+}
+
+impl IssuePriority {
+    pub fn value(self) -> i32 {
+        self as i32
+    }
+
+    /// Convert the numeric priority returned by the API.
+    pub fn from_value(value: f64) -> Self {
+        match value as i32 {
+            1 => Self::Urgent,
+            2 => Self::High,
+            3 => Self::Medium,
+            4 => Self::Low,
+            _ => Self::None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Urgent => "urgent",
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+        }
+    }
+}
+
+impl std::fmt::Display for IssuePriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for IssuePriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" | "no" | "0" => Ok(Self::None),
+            "urgent" | "1" => Ok(Self::Urgent),
+            "high" | "2" => Ok(Self::High),
+            "medium" | "normal" | "3" => Ok(Self::Medium),
+            "low" | "4" => Ok(Self::Low),
+            _ => Err(format!(
+                "invalid priority '{}': expected none, urgent, high, medium, low or 0-4",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
 pub enum IssueTableColumns {
     Key,
+    Id,
+    Number,
     Title,
-    #[allow(dead_code)]
     Description,
     State,
+    Assignee,
+    Team,
+    Priority,
+    Estimate,
+    Due,
+    Labels,
+    Project,
+    Cycle,
+    Parent,
+    Branch,
+    Url,
     Created,
     Updated,
-    AssigneeDisplayName,
+    Started,
+    TriageStarted,
+    Triaged,
+    Completed,
+    Canceled,
+    Trashed,
 }
 
 impl Render for linear_api::schema::issue_list::Issue {
@@ -49,7 +162,24 @@ impl Render for linear_api::schema::issue_list::Issue {
             IssueTableColumns::Key,
             IssueTableColumns::Title,
             IssueTableColumns::State,
-            IssueTableColumns::AssigneeDisplayName,
+            IssueTableColumns::Assignee,
+            IssueTableColumns::Created,
+            IssueTableColumns::Updated,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            IssueTableColumns::Key,
+            IssueTableColumns::Title,
+            IssueTableColumns::State,
+            IssueTableColumns::Priority,
+            IssueTableColumns::Assignee,
+            IssueTableColumns::Labels,
+            IssueTableColumns::Estimate,
+            IssueTableColumns::Due,
+            IssueTableColumns::Project,
+            IssueTableColumns::Cycle,
             IssueTableColumns::Created,
             IssueTableColumns::Updated,
         ]
@@ -60,7 +190,7 @@ impl Render for linear_api::schema::issue_list::Issue {
             IssueTableColumns::Key,
             IssueTableColumns::Title,
             IssueTableColumns::State,
-            IssueTableColumns::AssigneeDisplayName,
+            IssueTableColumns::Assignee,
             IssueTableColumns::Description,
             IssueTableColumns::Created,
             IssueTableColumns::Updated,
@@ -70,49 +200,101 @@ impl Render for linear_api::schema::issue_list::Issue {
     fn render_field_header(field: &Self::Fields) -> Cell {
         match field {
             IssueTableColumns::Key => Cell::new("Key").add_attribute(Attribute::Bold),
+            IssueTableColumns::Id => Cell::new("ID"),
+            IssueTableColumns::Number => Cell::new("Number"),
             IssueTableColumns::Title => Cell::new("Title"),
             IssueTableColumns::Description => Cell::new("Description"),
             IssueTableColumns::State => Cell::new("State"),
-            IssueTableColumns::AssigneeDisplayName => Cell::new("Assignee"),
+            IssueTableColumns::Assignee => Cell::new("Assignee"),
+            IssueTableColumns::Team => Cell::new("Team"),
+            IssueTableColumns::Priority => Cell::new("Priority"),
+            IssueTableColumns::Estimate => Cell::new("Estimate"),
+            IssueTableColumns::Due => Cell::new("Due"),
+            IssueTableColumns::Labels => Cell::new("Labels"),
+            IssueTableColumns::Project => Cell::new("Project"),
+            IssueTableColumns::Cycle => Cell::new("Cycle"),
+            IssueTableColumns::Parent => Cell::new("Parent"),
+            IssueTableColumns::Branch => Cell::new("Branch"),
+            IssueTableColumns::Url => Cell::new("URL"),
             IssueTableColumns::Created => Cell::new("Created"),
             IssueTableColumns::Updated => Cell::new("Updated"),
+            IssueTableColumns::Started => Cell::new("Started"),
+            IssueTableColumns::TriageStarted => Cell::new("Triage started"),
+            IssueTableColumns::Triaged => Cell::new("Triaged"),
+            IssueTableColumns::Completed => Cell::new("Completed"),
+            IssueTableColumns::Canceled => Cell::new("Canceled"),
+            IssueTableColumns::Trashed => Cell::new("Trashed"),
         }
     }
 
     fn render_field(item: &Self, field: Self::Fields) -> Cell {
-        let timeformat = time::format_description::parse("[year]-[month]-[day]").unwrap();
+        let date = |value: Option<&DateTime>| Cell::new(value.map(format_date).unwrap_or_default());
         match field {
             IssueTableColumns::Key => Cell::new(&item.identifier),
+            IssueTableColumns::Id => Cell::new(item.id.inner()),
+            IssueTableColumns::Number => Cell::new(item.number),
             IssueTableColumns::Title => Cell::new(&item.title),
-            IssueTableColumns::Description => {
-                Cell::new(item.description.as_deref().unwrap_or_default())
-            }
+            IssueTableColumns::Description => Cell::new(markdown::render(
+                item.description.as_deref().unwrap_or_default(),
+            )),
             IssueTableColumns::State => Cell::new(&item.state.name),
-            IssueTableColumns::AssigneeDisplayName => Cell::new(
+            IssueTableColumns::Assignee => Cell::new(
                 item.assignee
                     .as_ref()
                     .map(|x| x.display_name.as_str())
                     .unwrap_or_default(),
             ),
-            IssueTableColumns::Created => {
-                let v = item
-                    .created_at
-                    .parse()
-                    .unwrap()
-                    .format(&timeformat)
-                    .unwrap();
-                Cell::new(v)
-            }
-
-            IssueTableColumns::Updated => {
-                let v = item
-                    .updated_at
-                    .parse()
-                    .unwrap()
-                    .format(&timeformat)
-                    .unwrap();
-                Cell::new(v)
+            IssueTableColumns::Team => Cell::new(&item.team.key),
+            IssueTableColumns::Priority => Cell::new(&item.priority_label),
+            IssueTableColumns::Estimate => {
+                Cell::new(item.estimate.map(|e| e.to_string()).unwrap_or_default())
             }
+            IssueTableColumns::Due => Cell::new(
+                item.due_date
+                    .as_ref()
+                    .map(|d| d.0.as_str())
+                    .unwrap_or_default(),
+            ),
+            IssueTableColumns::Labels => Cell::new(
+                item.labels
+                    .nodes
+                    .iter()
+                    .map(|l| l.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            IssueTableColumns::Project => Cell::new(
+                item.project
+                    .as_ref()
+                    .map(|p| p.name.as_str())
+                    .unwrap_or_default(),
+            ),
+            IssueTableColumns::Cycle => Cell::new(
+                item.cycle
+                    .as_ref()
+                    .map(|c| cycle_title(c.number, c.name.as_deref()))
+                    .unwrap_or_default(),
+            ),
+            IssueTableColumns::Parent => Cell::new(
+                item.parent
+                    .as_ref()
+                    .map(|p| p.identifier.as_str())
+                    .unwrap_or_default(),
+            ),
+            IssueTableColumns::Branch => Cell::new(&item.branch_name),
+            IssueTableColumns::Url => Cell::new(&item.url),
+            IssueTableColumns::Created => date(Some(&item.created_at)),
+            IssueTableColumns::Updated => date(Some(&item.updated_at)),
+            IssueTableColumns::Started => date(item.started_at.as_ref()),
+            IssueTableColumns::TriageStarted => date(item.started_triage_at.as_ref()),
+            IssueTableColumns::Triaged => date(item.triaged_at.as_ref()),
+            IssueTableColumns::Completed => date(item.completed_at.as_ref()),
+            IssueTableColumns::Canceled => date(item.canceled_at.as_ref()),
+            IssueTableColumns::Trashed => Cell::new(if item.trashed == Some(true) {
+                "yes"
+            } else {
+                ""
+            }),
         }
     }
 }
@@ -0,0 +1,1087 @@
+//! Compact query language for selecting issues.
+//!
+//! A query is a list of `key:value` terms, eg:
+//!
+//! ```text
+//! state:started label:bug priority:>=2 updated:>-7d project:"Foo" -assignee:none
+//! ```
+//!
+//! Terms are combined with AND, unless separated by OR. `NOT` or a leading
+//! `-` negates a term or a parenthesized group. Multiple comma separated
+//! values match any of them (`state:started,unstarted`), and words without a
+//! key search the issue content.
+//!
+//! The Linear API has no NOT filter, so negations are pushed down to the
+//! individual comparators.
+
+use std::collections::HashMap;
+
+use linear_api::schema::issue_list::{
+    BooleanComparator, ContentComparator, DateComparator, DateTimeOrDuration, EstimateComparator,
+    Idcomparator, IssueFilter, IssueLabelCollectionFilter, IssueLabelFilter, NullableCycleFilter,
+    NullableDateComparator, NullableIssueFilter, NullableNumberComparator, NullableProjectFilter,
+    NullableTimelessDateComparator, NullableUserFilter, NumberComparator, StringComparator,
+    TeamFilter, TimelessDateOrDuration, WorkflowStateFilter,
+};
+
+use crate::util;
+
+use super::IssuePriority;
+
+/// IDs of the labels matching "Group/Label" values of label terms, see
+/// [`group_labels`].
+pub type GroupLabelIds = HashMap<String, Vec<cynic::Id>>;
+
+/// Keys supported in `key:value` terms.
+const KEYS: &[&str] = &[
+    "assignee",
+    "creator",
+    "team",
+    "state",
+    "label",
+    "priority",
+    "estimate",
+    "project",
+    "cycle",
+    "parent",
+    "title",
+    "search",
+    "created",
+    "updated",
+    "started",
+    "completed",
+    "canceled",
+    "due",
+];
+
+const STATE_TYPES: &[&str] = &[
+    "triage",
+    "backlog",
+    "unstarted",
+    "started",
+    "completed",
+    "canceled",
+];
+
+/// Byte range of a token in the query.
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+/// A query that could not be parsed.
+///
+/// Displays the query with the offending part underlined.
+#[derive(Debug)]
+pub struct QueryError {
+    query: String,
+    span: Span,
+    message: String,
+}
+
+impl std::fmt::Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offset = self.query[..self.span.start].chars().count();
+        let width = self.query[self.span.start..self.span.end].chars().count();
+        writeln!(f, "invalid filter: {}", self.message)?;
+        writeln!(f, "  {}", self.query)?;
+        write!(f, "  {}{}", " ".repeat(offset), "^".repeat(width.max(1)))
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// The "Group/Label" values of label terms.
+///
+/// A label without a parent can not be matched by the API, so negations of
+/// labels in a group exclude the label IDs instead. Invalid terms are
+/// skipped, [`parse`] reports them.
+pub fn group_labels(query: &str) -> Vec<String> {
+    let Ok(tokens) = lex(query) else {
+        return Vec::new();
+    };
+    tokens
+        .iter()
+        .filter(|token| token.kind == TokenKind::Term)
+        .filter_map(|token| parse_term(token.text, token.span).ok())
+        .filter(|term| term.key == "label")
+        .flat_map(|term| term.values)
+        .map(|(value, _)| value)
+        .filter(|value| value.contains('/'))
+        .collect()
+}
+
+/// Parse a query into an issue filter.
+///
+/// `labels` has the IDs of the labels returned by [`group_labels`].
+pub fn parse(query: &str, labels: &GroupLabelIds) -> Result<IssueFilter, QueryError> {
+    let error = |span, message: String| QueryError {
+        query: query.to_string(),
+        span,
+        message,
+    };
+
+    let tokens = lex(query).map_err(|(span, message)| error(span, message))?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        end: Span::new(query.len(), query.len()),
+    };
+    let expr = parser
+        .parse_query()
+        .map_err(|(span, message)| error(span, message))?;
+    eval(&expr, false, labels).map_err(|(span, message)| error(span, message))
+}
+
+type Parsed<T> = Result<T, (Span, String)>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    Term,
+}
+
+#[derive(Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    span: Span,
+}
+
+fn lex(query: &str) -> Parsed<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let (kind, end) = match c {
+            c if c.is_whitespace() => continue,
+            '(' => (TokenKind::LParen, start + 1),
+            ')' => (TokenKind::RParen, start + 1),
+            '-' if chars
+                .peek()
+                .is_some_and(|(_, next)| !next.is_whitespace() && *next != ')') =>
+            {
+                (TokenKind::Not, start + 1)
+            }
+            _ => {
+                let mut end = start + c.len_utf8();
+                let mut quote = (c == '"').then_some(start);
+                while let Some(&(i, c)) = chars.peek() {
+                    if quote.is_none() && (c.is_whitespace() || c == '(' || c == ')') {
+                        break;
+                    }
+                    if c == '"' {
+                        quote = match quote {
+                            Some(_) => None,
+                            None => Some(i),
+                        };
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                if let Some(quote) = quote {
+                    return Err((Span::new(quote, end), "unterminated quote".to_string()));
+                }
+                let kind = match &query[start..end] {
+                    "AND" => TokenKind::And,
+                    "OR" => TokenKind::Or,
+                    "NOT" => TokenKind::Not,
+                    _ => TokenKind::Term,
+                };
+                (kind, end)
+            }
+        };
+        tokens.push(Token {
+            kind,
+            text: &query[start..end],
+            span: Span::new(start, end),
+        });
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug)]
+enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Term(Term),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Eq,
+    Neq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl Op {
+    fn negate(self) -> Self {
+        match self {
+            Op::Eq => Op::Neq,
+            Op::Neq => Op::Eq,
+            Op::Gt => Op::Lte,
+            Op::Gte => Op::Lt,
+            Op::Lt => Op::Gte,
+            Op::Lte => Op::Gt,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Term {
+    key: String,
+    /// The explicitly given operator.
+    op: Option<(Op, Span)>,
+    values: Vec<(String, Span)>,
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token<'a>],
+    pos: usize,
+    /// Position after the last token, for errors at the end of the query.
+    end: Span,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token<'a>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn parse_query(&mut self) -> Parsed<Expr> {
+        if self.tokens.is_empty() {
+            return Err((self.end, "empty filter".to_string()));
+        }
+        let expr = self.parse_or()?;
+        match self.peek() {
+            None => Ok(expr),
+            Some(token) => Err((token.span, "unmatched ')'".to_string())),
+        }
+    }
+
+    fn parse_or(&mut self) -> Parsed<Expr> {
+        let mut items = vec![self.parse_and()?];
+        while self.peek().is_some_and(|t| t.kind == TokenKind::Or) {
+            self.pos += 1;
+            items.push(self.parse_and()?);
+        }
+        Ok(if items.len() == 1 {
+            items.pop().unwrap()
+        } else {
+            Expr::Or(items)
+        })
+    }
+
+    fn parse_and(&mut self) -> Parsed<Expr> {
+        let mut items = vec![self.parse_unary()?];
+        loop {
+            match self.peek().map(|t| t.kind) {
+                None | Some(TokenKind::Or | TokenKind::RParen) => break,
+                Some(TokenKind::And) => self.pos += 1,
+                _ => {}
+            }
+            items.push(self.parse_unary()?);
+        }
+        Ok(if items.len() == 1 {
+            items.pop().unwrap()
+        } else {
+            Expr::And(items)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Parsed<Expr> {
+        let Some(token) = self.peek() else {
+            let message = match self.tokens.last() {
+                Some(last) => format!("expected a filter after '{}'", last.text),
+                None => "expected a filter".to_string(),
+            };
+            return Err((self.end, message));
+        };
+        let span = token.span;
+        self.pos += 1;
+
+        match token.kind {
+            TokenKind::Not => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            TokenKind::LParen => {
+                let expr = self.parse_or()?;
+                match self.peek() {
+                    Some(t) if t.kind == TokenKind::RParen => {
+                        self.pos += 1;
+                        Ok(expr)
+                    }
+                    _ => Err((span, "unclosed '('".to_string())),
+                }
+            }
+            TokenKind::Term => parse_term(token.text, span).map(Expr::Term),
+            TokenKind::RParen => Err((span, "unexpected ')'".to_string())),
+            TokenKind::And | TokenKind::Or => {
+                Err((span, format!("expected a filter before '{}'", token.text)))
+            }
+        }
+    }
+}
+
+/// Parse a `key:[op]value[,value...]` term, or a bare search word.
+fn parse_term(text: &str, span: Span) -> Parsed<Term> {
+    let Some(colon) = find_unquoted(text, ':') else {
+        return Ok(Term {
+            key: "search".to_string(),
+            op: None,
+            values: vec![(unquote(text), span)],
+        });
+    };
+
+    let key_span = Span::new(span.start, span.start + colon);
+    let key = match text[..colon].to_ascii_lowercase().as_str() {
+        "" => return Err((key_span, "missing key before ':'".to_string())),
+        "author" => "creator".to_string(),
+        key if KEYS.contains(&key) => key.to_string(),
+        key => {
+            return Err((
+                key_span,
+                format!("unknown key '{}' (supported: {})", key, KEYS.join(", ")),
+            ))
+        }
+    };
+
+    let mut rest = &text[colon + 1..];
+    let mut offset = span.start + colon + 1;
+    let op = [
+        (">=", Op::Gte),
+        ("<=", Op::Lte),
+        ("!=", Op::Neq),
+        (">", Op::Gt),
+        ("<", Op::Lt),
+        ("=", Op::Eq),
+    ]
+    .into_iter()
+    .find(|(prefix, _)| rest.starts_with(prefix))
+    .map(|(prefix, op)| {
+        let op_span = Span::new(offset, offset + prefix.len());
+        rest = &rest[prefix.len()..];
+        offset += prefix.len();
+        (op, op_span)
+    });
+
+    let mut values = Vec::new();
+    for part in split_unquoted(rest, ',') {
+        let value_span = Span::new(offset, offset + part.len());
+        offset += part.len() + 1;
+        let value = unquote(part);
+        if value.is_empty() {
+            return Err((value_span, format!("missing value for '{}'", key)));
+        }
+        values.push((value, value_span));
+    }
+
+    Ok(Term { key, op, values })
+}
+
+fn find_unquoted(text: &str, needle: char) -> Option<usize> {
+    let mut quoted = false;
+    text.char_indices().find_map(|(i, c)| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        (!quoted && c == needle).then_some(i)
+    })
+}
+
+fn split_unquoted(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(i) = find_unquoted(rest, separator) {
+        parts.push(&rest[..i]);
+        rest = &rest[i + separator.len_utf8()..];
+    }
+    parts.push(rest);
+    parts
+}
+
+fn unquote(text: &str) -> String {
+    text.replace('"', "")
+}
+
+fn combine(mut filters: Vec<IssueFilter>, any: bool) -> IssueFilter {
+    if filters.len() == 1 {
+        filters.pop().unwrap()
+    } else if any {
+        IssueFilter::new_or(filters)
+    } else {
+        IssueFilter::new_and(filters)
+    }
+}
+
+/// Convert an expression into a filter, negating it if requested.
+fn eval(expr: &Expr, negate: bool, labels: &GroupLabelIds) -> Parsed<IssueFilter> {
+    match expr {
+        Expr::And(items) | Expr::Or(items) => {
+            let filters = items
+                .iter()
+                .map(|item| eval(item, negate, labels))
+                .collect::<Parsed<Vec<_>>>()?;
+            // De Morgan: a negated AND becomes an OR of negations.
+            Ok(combine(filters, matches!(expr, Expr::Or(_)) != negate))
+        }
+        Expr::Not(expr) => eval(expr, !negate, labels),
+        Expr::Term(term) => {
+            let filters = term
+                .values
+                .iter()
+                .map(|(value, span)| term_filter(term, value, *span, negate, labels))
+                .collect::<Parsed<Vec<_>>>()?;
+            Ok(combine(filters, !negate))
+        }
+    }
+}
+
+/// Set the comparator field matching the operator.
+macro_rules! compare {
+    ($ty:ty, $op:expr, $value:expr) => {{
+        let mut comparator = <$ty>::default();
+        let value = Some($value);
+        match $op {
+            Op::Eq => comparator.eq = value,
+            Op::Neq => comparator.neq = value,
+            Op::Gt => comparator.gt = value,
+            Op::Gte => comparator.gte = value,
+            Op::Lt => comparator.lt = value,
+            Op::Lte => comparator.lte = value,
+        }
+        comparator
+    }};
+}
+
+fn string_exact(value: &str, negate: bool) -> StringComparator {
+    if negate {
+        StringComparator {
+            neq: Some(value.to_string()),
+            ..Default::default()
+        }
+    } else {
+        StringComparator::eq(value.to_string())
+    }
+}
+
+fn string_eq(value: &str, negate: bool) -> StringComparator {
+    if negate {
+        StringComparator {
+            neq_ignore_case: Some(value.to_string()),
+            ..Default::default()
+        }
+    } else {
+        StringComparator::eq_ignore_case(value.to_string())
+    }
+}
+
+fn is_none(value: &str) -> bool {
+    value.eq_ignore_ascii_case("none")
+}
+
+/// A negated filter on an optional relation also matches issues without it.
+fn or_null(filter: IssueFilter, null: IssueFilter, negate: bool) -> IssueFilter {
+    if negate {
+        IssueFilter::new_or(vec![null, filter])
+    } else {
+        filter
+    }
+}
+
+/// Build the filter for a single value of a term.
+fn term_filter(
+    term: &Term,
+    value: &str,
+    span: Span,
+    negate: bool,
+    labels: &GroupLabelIds,
+) -> Parsed<IssueFilter> {
+    let (op, negate) = match term.op {
+        Some((Op::Neq, _)) => (Op::Eq, !negate),
+        Some((op, _)) => (op, negate),
+        None => (Op::Eq, negate),
+    };
+    let key = term.key.as_str();
+    let comparable = matches!(
+        key,
+        "priority"
+            | "estimate"
+            | "cycle"
+            | "created"
+            | "updated"
+            | "started"
+            | "completed"
+            | "canceled"
+            | "due"
+    );
+    if let Some((op, op_span)) = term.op {
+        if !comparable && op != Op::Neq && op != Op::Eq {
+            return Err((op_span, format!("'{}' does not support comparisons", key)));
+        }
+    }
+    let cmp = if negate { op.negate() } else { op };
+
+    let filter = match key {
+        "assignee" | "creator" => {
+            let user = |filter: NullableUserFilter| {
+                let filter = Some(Box::new(filter));
+                match key {
+                    "assignee" => IssueFilter {
+                        assignee: filter,
+                        ..Default::default()
+                    },
+                    _ => IssueFilter {
+                        creator: filter,
+                        ..Default::default()
+                    },
+                }
+            };
+            let null = |null: bool| {
+                user(NullableUserFilter {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            if is_none(value) {
+                null(!negate)
+            } else if value == "me" {
+                let me = user(NullableUserFilter {
+                    is_me: Some(BooleanComparator {
+                        eq: Some(!negate),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                });
+                or_null(me, null(true), negate)
+            } else {
+                let name = user(NullableUserFilter {
+                    display_name: Some(string_exact(value, negate)),
+                    ..Default::default()
+                });
+                or_null(name, null(true), negate)
+            }
+        }
+        "team" => IssueFilter {
+            team: Some(Box::new(TeamFilter {
+                key: Some(string_eq(value, negate)),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+        "state" => {
+            let lower = value.to_ascii_lowercase();
+            let state = if STATE_TYPES.contains(&lower.as_str()) {
+                WorkflowStateFilter {
+                    type_: Some(string_exact(&lower, negate)),
+                    ..Default::default()
+                }
+            } else {
+                WorkflowStateFilter {
+                    name: Some(string_eq(value, negate)),
+                    ..Default::default()
+                }
+            };
+            IssueFilter {
+                state: Some(Box::new(state)),
+                ..Default::default()
+            }
+        }
+        "label" => {
+            let labels = if is_none(value) {
+                let count = if negate { Op::Gt } else { Op::Eq };
+                IssueLabelCollectionFilter {
+                    length: Some(compare!(NumberComparator, count, 0.0)),
+                    ..Default::default()
+                }
+            } else if negate {
+                IssueLabelCollectionFilter {
+                    every: Some(Box::new(not_label(value, labels))),
+                    ..Default::default()
+                }
+            } else {
+                return Ok(IssueFilter::label(value.to_string()));
+            };
+            IssueFilter {
+                labels: Some(Box::new(labels)),
+                ..Default::default()
+            }
+        }
+        "priority" => {
+            let priority = value.parse::<IssuePriority>().map_err(|err| (span, err))?;
+            IssueFilter {
+                priority: Some(compare!(
+                    NullableNumberComparator,
+                    cmp,
+                    priority.value() as f64
+                )),
+                ..Default::default()
+            }
+        }
+        "estimate" => {
+            let estimate = |comparator| IssueFilter {
+                estimate: Some(comparator),
+                ..Default::default()
+            };
+            let null = |null| {
+                estimate(EstimateComparator {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            if is_none(value) {
+                null(!negate)
+            } else {
+                let points = parse_number(value, span)?;
+                or_null(
+                    estimate(compare!(EstimateComparator, cmp, points)),
+                    null(true),
+                    negate,
+                )
+            }
+        }
+        "project" => {
+            let project = |filter| IssueFilter {
+                project: Some(Box::new(filter)),
+                ..Default::default()
+            };
+            let null = |null| {
+                project(NullableProjectFilter {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            if is_none(value) {
+                null(!negate)
+            } else if negate {
+                let filter = NullableProjectFilter {
+                    name: Some(string_eq(value, true)),
+                    slug_id: Some(string_exact(value, true)),
+                    ..Default::default()
+                };
+                or_null(project(filter), null(true), true)
+            } else {
+                project(NullableProjectFilter {
+                    or: Some(vec![
+                        NullableProjectFilter {
+                            name: Some(string_eq(value, false)),
+                            ..Default::default()
+                        },
+                        NullableProjectFilter {
+                            slug_id: Some(string_exact(value, false)),
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                })
+            }
+        }
+        "cycle" => {
+            let cycle = |filter| IssueFilter {
+                cycle: Some(Box::new(filter)),
+                ..Default::default()
+            };
+            let null = |null| {
+                cycle(NullableCycleFilter {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            let flag = Some(BooleanComparator {
+                eq: Some(!negate),
+                ..Default::default()
+            });
+            let filter = match value.to_ascii_lowercase().as_str() {
+                "none" => return Ok(null(!negate)),
+                "current" | "active" => NullableCycleFilter {
+                    is_active: flag,
+                    ..Default::default()
+                },
+                "next" => NullableCycleFilter {
+                    is_next: flag,
+                    ..Default::default()
+                },
+                "previous" => NullableCycleFilter {
+                    is_previous: flag,
+                    ..Default::default()
+                },
+                _ => NullableCycleFilter {
+                    number: Some(compare!(NumberComparator, cmp, parse_number(value, span)?)),
+                    ..Default::default()
+                },
+            };
+            or_null(cycle(filter), null(true), negate)
+        }
+        "parent" => {
+            let parent = |filter| IssueFilter {
+                parent: Some(Box::new(filter)),
+                ..Default::default()
+            };
+            let null = |null| {
+                parent(NullableIssueFilter {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            if is_none(value) {
+                return Ok(null(!negate));
+            }
+            let (team, number) = value
+                .rsplit_once('-')
+                .and_then(|(team, number)| Some((team, number.parse::<f64>().ok()?)))
+                .ok_or_else(|| {
+                    (
+                        span,
+                        format!("invalid issue ID '{}': expected eg MYTEAM-123", value),
+                    )
+                })?;
+            let number = Some(compare!(NumberComparator, cmp, number));
+            let team = Some(Box::new(TeamFilter {
+                key: Some(string_eq(team, negate)),
+                ..Default::default()
+            }));
+            let filter = if negate {
+                NullableIssueFilter {
+                    or: Some(vec![
+                        NullableIssueFilter {
+                            number,
+                            ..Default::default()
+                        },
+                        NullableIssueFilter {
+                            team,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                }
+            } else {
+                NullableIssueFilter {
+                    number,
+                    team,
+                    ..Default::default()
+                }
+            };
+            or_null(parent(filter), null(true), negate)
+        }
+        "title" => {
+            let mut title = StringComparator::default();
+            if negate {
+                title.not_contains_ignore_case = Some(value.to_string());
+            } else {
+                title.contains_ignore_case = Some(value.to_string());
+            }
+            IssueFilter {
+                title: Some(title),
+                ..Default::default()
+            }
+        }
+        "search" => {
+            let mut content = ContentComparator::default();
+            if negate {
+                content.not_contains = Some(value.to_string());
+            } else {
+                content.contains = Some(value.to_string());
+            }
+            IssueFilter {
+                searchable_content: Some(content),
+                ..Default::default()
+            }
+        }
+        "created" | "updated" => {
+            // Dates without an operator match on or after the date.
+            let cmp = if term.op.is_none() {
+                if negate {
+                    Op::Lt
+                } else {
+                    Op::Gte
+                }
+            } else {
+                cmp
+            };
+            let date = compare!(DateComparator, cmp, date_time(value, span)?);
+            match key {
+                "created" => IssueFilter {
+                    created_at: Some(date),
+                    ..Default::default()
+                },
+                _ => IssueFilter {
+                    updated_at: Some(date),
+                    ..Default::default()
+                },
+            }
+        }
+        "started" | "completed" | "canceled" => {
+            let field = |comparator| match key {
+                "started" => IssueFilter {
+                    started_at: Some(comparator),
+                    ..Default::default()
+                },
+                "completed" => IssueFilter {
+                    completed_at: Some(comparator),
+                    ..Default::default()
+                },
+                _ => IssueFilter {
+                    canceled_at: Some(comparator),
+                    ..Default::default()
+                },
+            };
+            let null = |null| {
+                field(NullableDateComparator {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            if is_none(value) {
+                null(!negate)
+            } else {
+                let cmp = if term.op.is_none() {
+                    if negate {
+                        Op::Lt
+                    } else {
+                        Op::Gte
+                    }
+                } else {
+                    cmp
+                };
+                let date = compare!(NullableDateComparator, cmp, date_time(value, span)?);
+                or_null(field(date), null(true), negate)
+            }
+        }
+        "due" => {
+            let due = |comparator| IssueFilter {
+                due_date: Some(comparator),
+                ..Default::default()
+            };
+            let null = |null| {
+                due(NullableTimelessDateComparator {
+                    null: Some(null),
+                    ..Default::default()
+                })
+            };
+            if is_none(value) {
+                null(!negate)
+            } else {
+                let date = TimelessDateOrDuration(date_or_duration(value, span)?);
+                or_null(
+                    due(compare!(NullableTimelessDateComparator, cmp, date)),
+                    null(true),
+                    negate,
+                )
+            }
+        }
+        _ => unreachable!("unhandled filter key '{}'", key),
+    };
+    Ok(filter)
+}
+
+/// Labels not matching the given name, in "Label" or "Group/Label" form.
+fn not_label(name: &str, labels: &GroupLabelIds) -> IssueLabelFilter {
+    if name.contains('/') {
+        // Comparing the parent would also exclude labels without a group.
+        let ids = labels.get(name).cloned().unwrap_or_default();
+        return IssueLabelFilter {
+            id: Some(Idcomparator {
+                nin: Some(ids),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+    }
+    IssueLabelFilter {
+        name: Some(string_eq(name, true)),
+        ..Default::default()
+    }
+}
+
+fn parse_number(value: &str, span: Span) -> Parsed<f64> {
+    value
+        .parse()
+        .map_err(|_| (span, format!("invalid number '{}'", value)))
+}
+
+fn date_time(value: &str, span: Span) -> Parsed<DateTimeOrDuration> {
+    let value = date_or_duration(value, span)?;
+    // Absolute dates need a time for timestamp comparisons.
+    if value.starts_with(['P', '-']) {
+        Ok(DateTimeOrDuration(value))
+    } else {
+        let date = util::parse_date_time(&value).map_err(|err| (span, err.to_string()))?;
+        Ok(DateTimeOrDuration(date.0))
+    }
+}
+
+/// Parse a date (YYYY-MM-DD), "today", or a date relative to now as an
+/// ISO 8601 duration.
+///
+/// Relative dates have a sign, a number and a unit: h(ours), d(ays),
+/// w(eeks), m(onths) or y(ears). Eg: "-7d" is a week ago, "+2w" in two weeks.
+fn date_or_duration(value: &str, span: Span) -> Parsed<String> {
+    if value.eq_ignore_ascii_case("today") {
+        let format = time::format_description::parse("[year]-[month]-[day]").unwrap();
+        return Ok(time::OffsetDateTime::now_utc()
+            .date()
+            .format(&format)
+            .unwrap());
+    }
+
+    let invalid = || {
+        (
+            span,
+            format!(
+                "invalid date '{}': expected YYYY-MM-DD, today or a relative date like -7d or +2w",
+                value
+            ),
+        )
+    };
+    let Some(sign) = value.chars().next().filter(|c| matches!(c, '-' | '+')) else {
+        return util::parse_date(value)
+            .map(|date| date.0)
+            .map_err(|_| invalid());
+    };
+    let rest = &value[1..];
+    let Some(unit) = rest.chars().last() else {
+        return Err(invalid());
+    };
+    let amount = &rest[..rest.len() - unit.len_utf8()];
+    if amount.is_empty() || !amount.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let duration = match unit {
+        'h' => format!("PT{}H", amount),
+        'd' => format!("P{}D", amount),
+        'w' => format!("P{}W", amount),
+        'm' => format!("P{}M", amount),
+        'y' => format!("P{}Y", amount),
+        _ => return Err(invalid()),
+    };
+    Ok(match sign {
+        '-' => format!("-{}", duration),
+        _ => duration,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn filter(query: &str) -> Value {
+        let labels = GroupLabelIds::from([("Group/Bug".to_string(), vec![cynic::Id::new("bug")])]);
+        let filter = parse(query, &labels).unwrap_or_else(|err| panic!("{}", err));
+        serde_json::to_value(filter).unwrap()
+    }
+
+    /// The underlined part of the query and the message.
+    fn error(query: &str) -> (&str, String) {
+        let err = parse(query, &GroupLabelIds::new()).unwrap_err();
+        (&query[err.span.start..err.span.end], err.message)
+    }
+
+    #[test]
+    fn error_spans() {
+        assert_eq!(
+            error("state:started (label:bug OR label:ui"),
+            ("(", "unclosed '('".to_string())
+        );
+
+        let (span, message) = error("label:bug colour:red");
+        assert_eq!(span, "colour");
+        assert!(message.starts_with("unknown key 'colour'"), "{}", message);
+
+        let (span, message) = error("priority:>x");
+        assert_eq!(span, "x");
+        assert!(message.starts_with("invalid priority 'x'"), "{}", message);
+
+        assert_eq!(
+            error(r#"title:"login page"#),
+            (r#""login page"#, "unterminated quote".to_string())
+        );
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert_eq!(
+            filter("state:started team:ENG OR label:bug"),
+            json!({"or": [
+                {"and": [
+                    {"state": {"type": {"eq": "started"}}},
+                    {"team": {"key": {"eqIgnoreCase": "ENG"}}},
+                ]},
+                {"labels": {"some": {"name": {"eqIgnoreCase": "bug"}}}},
+            ]})
+        );
+        assert_eq!(
+            filter("state:started AND (team:ENG OR label:bug)"),
+            json!({"and": [
+                {"state": {"type": {"eq": "started"}}},
+                {"or": [
+                    {"team": {"key": {"eqIgnoreCase": "ENG"}}},
+                    {"labels": {"some": {"name": {"eqIgnoreCase": "bug"}}}},
+                ]},
+            ]})
+        );
+    }
+
+    #[test]
+    fn negated_values_must_all_differ() {
+        assert_eq!(
+            filter("-state:started,unstarted"),
+            json!({"and": [
+                {"state": {"type": {"neq": "started"}}},
+                {"state": {"type": {"neq": "unstarted"}}},
+            ]})
+        );
+        assert_eq!(
+            filter("NOT state:started,unstarted"),
+            filter("-state:started,unstarted")
+        );
+        assert_eq!(
+            filter("-(state:started OR label:bug)"),
+            json!({"and": [
+                {"state": {"type": {"neq": "started"}}},
+                {"labels": {"every": {"name": {"neqIgnoreCase": "bug"}}}},
+            ]})
+        );
+    }
+
+    #[test]
+    fn negated_assignee_matches_unassigned() {
+        assert_eq!(
+            filter("-assignee:me"),
+            json!({"or": [
+                {"assignee": {"null": true}},
+                {"assignee": {"isMe": {"eq": false}}},
+            ]})
+        );
+        assert_eq!(
+            filter("-assignee:none"),
+            json!({"assignee": {"null": false}})
+        );
+    }
+
+    #[test]
+    fn negated_group_label_excludes_label_ids() {
+        assert_eq!(
+            filter("-label:Group/Bug"),
+            json!({"labels": {"every": {"id": {"nin": ["bug"]}}}})
+        );
+        assert_eq!(
+            group_labels("label:bug,Group/Bug -label:\"A/B\""),
+            ["Group/Bug", "A/B"]
+        );
+    }
+}
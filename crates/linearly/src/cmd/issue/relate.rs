@@ -0,0 +1,199 @@
+use anyhow::{bail, Context};
+use cynic::{MutationBuilder, QueryBuilder};
+
+use linear_api::schema::relations::{
+    IssueRelationCreate, IssueRelationCreateInput, IssueRelationCreateVariables,
+    IssueRelationDelete, IssueRelationIdVariables, IssueRelationType, IssueRelationsGet,
+    IssueRelationsVariables, Relation,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve,
+};
+
+/// Kind of relation, as seen from the first issue.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelationKind {
+    Blocks,
+    BlockedBy,
+    Duplicates,
+    RelatesTo,
+}
+
+impl RelationKind {
+    /// The relation type, and whether the issues have to be swapped.
+    ///
+    /// Linear only stores "blocks" relations, so "A blocked-by B" is stored
+    /// as "B blocks A".
+    fn relation_type(self) -> (IssueRelationType, bool) {
+        match self {
+            Self::Blocks => (IssueRelationType::Blocks, false),
+            Self::BlockedBy => (IssueRelationType::Blocks, true),
+            Self::Duplicates => (IssueRelationType::Duplicate, false),
+            Self::RelatesTo => (IssueRelationType::Related, false),
+        }
+    }
+
+    /// Returns true if `relation` is of this kind, as seen from `issue_id`.
+    fn matches(self, relation: &Relation, issue_id: &cynic::Id) -> bool {
+        let outgoing = relation.issue.id == *issue_id;
+        match (self, relation.type_.as_str()) {
+            (Self::Blocks, "blocks") => outgoing,
+            (Self::BlockedBy, "blocks") => !outgoing,
+            (Self::Duplicates, "duplicate") => outgoing,
+            (Self::RelatesTo, "related") => true,
+            _ => false,
+        }
+    }
+}
+
+/// Describe a relation (eg: "ENG-1 blocks ENG-2").
+fn describe(relation: &Relation) -> String {
+    let verb = match relation.type_.as_str() {
+        "blocks" => "blocks",
+        "duplicate" => "duplicates",
+        "related" => "relates to",
+        other => other,
+    };
+    format!(
+        "{} {} {}",
+        relation.issue.identifier, verb, relation.related_issue.identifier
+    )
+}
+
+/// Add a relation between two issues.
+///
+/// Example: `linear issue relate ENG-1 blocks ENG-2`
+#[derive(clap::Parser)]
+pub struct CmdIssueRelate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+
+    /// Kind of relation.
+    #[clap(value_enum)]
+    pub kind: RelationKind,
+
+    /// Related issue ID (eg: MYTEAM-124)
+    pub related_issue_id: String,
+}
+
+impl CliCommand for CmdIssueRelate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let issue = resolve::issue(&client, &self.issue_id).await?;
+        let related = resolve::issue(&client, &self.related_issue_id).await?;
+        if issue.id == related.id {
+            bail!("an issue can not be related to itself");
+        }
+
+        let (type_, swap) = self.kind.relation_type();
+        let (from, to) = if swap {
+            (related, issue)
+        } else {
+            (issue, related)
+        };
+        let input = IssueRelationCreateInput {
+            type_,
+            issue_id: from.id.into_inner(),
+            related_issue_id: to.id.into_inner(),
+        };
+
+        let payload = client
+            .query(IssueRelationCreate::build(IssueRelationCreateVariables {
+                input,
+            }))
+            .await
+            .with_context(|| {
+                format!(
+                    "could not relate {} to {}",
+                    self.issue_id, self.related_issue_id
+                )
+            })?
+            .issue_relation_create;
+        if !payload.success {
+            bail!("creating the relation failed");
+        }
+
+        let relation = payload.issue_relation;
+        match self.format.resolve() {
+            Format::Table => eprintln!("Added relation: {}", describe(&relation)),
+            format => println!("{}", render::render_value(&relation, format)?),
+        }
+
+        Ok(())
+    }
+}
+
+/// Remove the relations between two issues.
+#[derive(clap::Parser)]
+pub struct CmdIssueUnrelate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Only remove relations of this kind, as seen from the first issue.
+    #[clap(long, short, value_enum)]
+    pub kind: Option<RelationKind>,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+
+    /// Related issue ID (eg: MYTEAM-124)
+    pub related_issue_id: String,
+}
+
+impl CliCommand for CmdIssueUnrelate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let related = resolve::issue(&client, &self.related_issue_id).await?;
+        let issue = client
+            .query(IssueRelationsGet::build(IssueRelationsVariables {
+                id: self.issue_id.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not load issue {}", self.issue_id))?
+            .issue;
+
+        let relations = issue
+            .relations
+            .nodes
+            .iter()
+            .chain(&issue.inverse_relations.nodes)
+            .filter(|r| r.issue.id == related.id || r.related_issue.id == related.id)
+            .filter(|r| self.kind.is_none_or(|kind| kind.matches(r, &issue.id)))
+            .collect::<Vec<_>>();
+        if relations.is_empty() {
+            bail!(
+                "no matching relation between {} and {}",
+                issue.identifier,
+                related.identifier
+            );
+        }
+
+        for relation in relations {
+            let payload = client
+                .query(IssueRelationDelete::build(IssueRelationIdVariables {
+                    id: relation.id.clone().into_inner(),
+                }))
+                .await
+                .with_context(|| format!("could not remove relation: {}", describe(relation)))?
+                .issue_relation_delete;
+            if !payload.success {
+                bail!("removing the relation failed: {}", describe(relation));
+            }
+            eprintln!("Removed relation: {}", describe(relation));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::{
+    issue_update::{IssueUpdate, IssueUpdateInput, IssueUpdateVariables},
+    Nullable,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve, util,
+};
+
+/// Move issues below another issue, or make them top-level issues.
+#[derive(clap::Parser)]
+pub struct CmdIssueReparent {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Issue IDs (eg: MYTEAM-123).
+    ///
+    /// Use "-" to read whitespace separated IDs from stdin.
+    #[clap(required = true)]
+    pub issue_ids: Vec<String>,
+
+    /// New parent issue ID (eg: MYTEAM-100).
+    #[clap(long, short, required_unless_present = "none", conflicts_with = "none")]
+    pub parent: Option<String>,
+
+    /// Remove the parent, making the issues top-level issues.
+    #[clap(long)]
+    pub none: bool,
+}
+
+impl CliCommand for CmdIssueReparent {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        let parent = match &self.parent {
+            Some(id) => Some(resolve::issue(&client, id).await?),
+            None => None,
+        };
+
+        let mut updated = Vec::new();
+        for id in util::issue_ids(&self.issue_ids)? {
+            let issue = resolve::issue(&client, &id).await?;
+            if parent.as_ref().is_some_and(|p| p.id == issue.id) {
+                bail!("{} can not be its own parent", issue.identifier);
+            }
+            let parent_id = match &parent {
+                Some(parent) => Nullable::Value(parent.id.clone().into_inner()),
+                None => Nullable::Null,
+            };
+
+            let payload = client
+                .query(IssueUpdate::build(IssueUpdateVariables {
+                    id: issue.id.into_inner(),
+                    input: IssueUpdateInput {
+                        parent_id: Some(parent_id),
+                        ..Default::default()
+                    },
+                }))
+                .await
+                .with_context(|| format!("could not update issue {}", issue.identifier))?
+                .issue_update;
+            let issue = match payload.issue {
+                Some(issue) if payload.success => issue,
+                _ => bail!("issue update failed"),
+            };
+
+            if let Format::Table = self.format.resolve() {
+                match &parent {
+                    Some(parent) => {
+                        eprintln!("Moved {} below {}", issue.identifier, parent.identifier)
+                    }
+                    None => eprintln!("Removed the parent of {}", issue.identifier),
+                }
+            }
+            updated.push(issue);
+        }
+
+        if !matches!(self.format.resolve(), Format::Table) {
+            println!("{}", render::render_value(&updated, self.format)?);
+        }
+
+        Ok(())
+    }
+}
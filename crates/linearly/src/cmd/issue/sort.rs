@@ -0,0 +1,159 @@
+use linear_api::schema::issue_list::{
+    AssigneeSort, CompletedAtSort, CreatedAtSort, CycleSort, DueDateSort, EstimateSort,
+    IssueSortInput, LabelSort, ManualSort, MilestoneSort, PaginationNulls, PaginationSortOrder,
+    PrioritySort, ProjectSort, SlaStatusSort, TeamSort, TitleSort, UpdatedAtSort,
+    WorkflowStateSort,
+};
+
+/// Field to sort issues by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IssueSortField {
+    Priority,
+    Estimate,
+    Title,
+    Label,
+    Sla,
+    Created,
+    Updated,
+    Completed,
+    Due,
+    Cycle,
+    Milestone,
+    Assignee,
+    Project,
+    Team,
+    Manual,
+    State,
+}
+
+impl IssueSortField {
+    const ALL: &'static [(&'static str, Self)] = &[
+        ("priority", Self::Priority),
+        ("estimate", Self::Estimate),
+        ("title", Self::Title),
+        ("label", Self::Label),
+        ("sla", Self::Sla),
+        ("created", Self::Created),
+        ("updated", Self::Updated),
+        ("completed", Self::Completed),
+        ("due", Self::Due),
+        ("cycle", Self::Cycle),
+        ("milestone", Self::Milestone),
+        ("assignee", Self::Assignee),
+        ("project", Self::Project),
+        ("team", Self::Team),
+        ("manual", Self::Manual),
+        ("state", Self::State),
+    ];
+}
+
+/// A sort field with its direction, given as "field" or "-field" for
+/// descending order.
+#[derive(Clone, Copy, Debug)]
+pub struct IssueSortKey {
+    pub field: IssueSortField,
+    pub descending: bool,
+}
+
+impl std::str::FromStr for IssueSortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, descending) = match s.trim().strip_prefix('-') {
+            Some(name) => (name, true),
+            None => (s.trim().trim_start_matches('+'), false),
+        };
+        let field = IssueSortField::ALL
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, field)| *field)
+            .ok_or_else(|| {
+                let names = IssueSortField::ALL
+                    .iter()
+                    .map(|(n, _)| *n)
+                    .collect::<Vec<_>>();
+                format!(
+                    "invalid sort field '{}': expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            })?;
+        Ok(Self { field, descending })
+    }
+}
+
+/// Where to put issues without a value for the sort field.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum SortNulls {
+    First,
+    Last,
+}
+
+impl IssueSortKey {
+    pub fn to_input(self, nulls: Option<SortNulls>) -> IssueSortInput {
+        let order = Some(if self.descending {
+            PaginationSortOrder::Descending
+        } else {
+            PaginationSortOrder::Ascending
+        });
+        let pagination_nulls = nulls.map(|nulls| match nulls {
+            SortNulls::First => PaginationNulls::First,
+            SortNulls::Last => PaginationNulls::Last,
+        });
+
+        macro_rules! sort {
+            ($field:ident, $ty:ident) => {
+                IssueSortInput {
+                    $field: Some($ty {
+                        order,
+                        nulls: pagination_nulls,
+                    }),
+                    ..Default::default()
+                }
+            };
+        }
+
+        match self.field {
+            // Issues without priority have the value 0, so they need to be
+            // placed explicitly.
+            IssueSortField::Priority => IssueSortInput {
+                priority: Some(PrioritySort {
+                    order,
+                    nulls: pagination_nulls,
+                    no_priority_first: nulls.map(|nulls| matches!(nulls, SortNulls::First)),
+                }),
+                ..Default::default()
+            },
+            IssueSortField::Estimate => sort!(estimate, EstimateSort),
+            IssueSortField::Title => sort!(title, TitleSort),
+            IssueSortField::Label => sort!(label, LabelSort),
+            IssueSortField::Sla => sort!(sla_status, SlaStatusSort),
+            IssueSortField::Created => sort!(created_at, CreatedAtSort),
+            IssueSortField::Updated => sort!(updated_at, UpdatedAtSort),
+            IssueSortField::Completed => sort!(completed_at, CompletedAtSort),
+            IssueSortField::Due => sort!(due_date, DueDateSort),
+            IssueSortField::Cycle => IssueSortInput {
+                cycle: Some(CycleSort {
+                    order,
+                    nulls: pagination_nulls,
+                    current_cycle_first: None,
+                }),
+                ..Default::default()
+            },
+            IssueSortField::Milestone => sort!(milestone, MilestoneSort),
+            IssueSortField::Assignee => sort!(assignee, AssigneeSort),
+            IssueSortField::Project => sort!(project, ProjectSort),
+            IssueSortField::Team => sort!(team, TeamSort),
+            IssueSortField::Manual => sort!(manual, ManualSort),
+            IssueSortField::State => sort!(workflow_state, WorkflowStateSort),
+        }
+    }
+}
+
+/// Build the sort inputs for the API, or `None` to use the default order.
+pub fn sort_inputs(keys: &[IssueSortKey], nulls: Option<SortNulls>) -> Option<Vec<IssueSortInput>> {
+    if keys.is_empty() {
+        return None;
+    }
+    Some(keys.iter().map(|key| key.to_input(nulls)).collect())
+}
@@ -0,0 +1,253 @@
+use std::fmt::Write;
+
+use anyhow::Context;
+use colored::Colorize;
+use cynic::QueryBuilder;
+use futures::{future::BoxFuture, FutureExt};
+
+use linear_api::{
+    schema::{
+        issue_list::Issue,
+        issue_tree::{IssueTreeGet, IssueTreeVariables, TreeIssue},
+    },
+    Client,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render,
+};
+
+/// Show the sub-issue hierarchy of an issue.
+///
+/// Each issue with sub-issues shows how many of its sub-issues (including
+/// nested ones) are done.
+#[derive(clap::Parser)]
+pub struct CmdIssueTree {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    /// Start at the top-most parent of the issue.
+    #[clap(long)]
+    pub root: bool,
+
+    /// Issue ID (eg: MYTEAM-123)
+    pub issue_id: String,
+}
+
+impl CliCommand for CmdIssueTree {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+
+        let mut issue = load(&client, &self.issue_id).await?;
+        if self.root {
+            while let Some(parent) = &issue.parent {
+                issue = load(&client, &parent.identifier).await?;
+            }
+        }
+        let highlight = Some(self.issue_id.as_str()).filter(|_| self.root);
+        let tree = build(&client, issue).await?;
+
+        if let Some(template) = &template {
+            println!("{}", template.render(&tree)?);
+        } else {
+            match self.format.resolve() {
+                Format::Table => print!("{}", render_tree(&tree, highlight)),
+                format => println!("{}", render::render_value(&tree, format)?),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A node of the sub-issue hierarchy.
+#[derive(serde::Serialize)]
+struct Node {
+    identifier: String,
+    title: String,
+    state: String,
+    state_type: String,
+    assignee: Option<String>,
+    estimate: Option<f64>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn is_done(&self) -> bool {
+        matches!(self.state_type.as_str(), "completed" | "canceled")
+    }
+
+    /// Number of done and total issues below this node.
+    fn progress(&self) -> (usize, usize) {
+        self.children.iter().fold((0, 0), |(done, total), child| {
+            let (child_done, child_total) = child.progress();
+            (
+                done + child_done + usize::from(child.is_done()),
+                total + child_total + 1,
+            )
+        })
+    }
+}
+
+async fn load(client: &Client, id: &str) -> Result<TreeIssue, anyhow::Error> {
+    Ok(client
+        .query(IssueTreeGet::build(IssueTreeVariables {
+            id: id.to_string(),
+        }))
+        .await
+        .with_context(|| format!("could not load issue {}", id))?
+        .issue)
+}
+
+/// Build the tree, loading every sub-issue that has sub-issues of its own.
+fn build(client: &Client, issue: TreeIssue) -> BoxFuture<'_, Result<Node, anyhow::Error>> {
+    async move {
+        let mut children = Vec::with_capacity(issue.children.nodes.len());
+        for child in issue.children.nodes {
+            let node = if child.children.nodes.is_empty() {
+                Node {
+                    identifier: child.identifier,
+                    title: child.title,
+                    state: child.state.name,
+                    state_type: child.state.type_,
+                    assignee: child.assignee.map(|u| u.display_name),
+                    estimate: child.estimate,
+                    children: Vec::new(),
+                }
+            } else {
+                build(client, load(client, &child.identifier).await?).await?
+            };
+            children.push(node);
+        }
+
+        Ok(Node {
+            identifier: issue.identifier,
+            title: issue.title,
+            state: issue.state.name,
+            state_type: issue.state.type_,
+            assignee: issue.assignee.map(|u| u.display_name),
+            estimate: issue.estimate,
+            children,
+        })
+    }
+    .boxed()
+}
+
+fn node_line(node: &Node, highlight: Option<&str>) -> String {
+    let mut line = format!(
+        "{} {} {}",
+        node.identifier.bold(),
+        node.title,
+        format!("[{}]", node.state).dimmed()
+    );
+    if let Some(assignee) = &node.assignee {
+        write!(line, " {}", format!("@{}", assignee).dimmed()).unwrap();
+    }
+    if !node.children.is_empty() {
+        let (done, total) = node.progress();
+        let percent = done * 100 / total;
+        let progress = format!("({}/{} done, {}%)", done, total, percent);
+        let progress = if done == total {
+            progress.green()
+        } else {
+            progress.yellow()
+        };
+        write!(line, " {}", progress).unwrap();
+    }
+    if node.is_done() {
+        line = line.dimmed().to_string();
+    }
+    if highlight.is_some_and(|id| id.eq_ignore_ascii_case(&node.identifier)) {
+        line = format!("{} {}", line, "◀".cyan());
+    }
+    line
+}
+
+fn render_tree(root: &Node, highlight: Option<&str>) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}", node_line(root, highlight)).unwrap();
+    render_children(&root.children, "", highlight, &mut out);
+    out
+}
+
+fn render_children(children: &[Node], indent: &str, highlight: Option<&str>, out: &mut String) {
+    for (index, child) in children.iter().enumerate() {
+        let last = index + 1 == children.len();
+        let (branch, next_indent) = if last {
+            ("└── ", format!("{}    ", indent))
+        } else {
+            ("├── ", format!("{}│   ", indent))
+        };
+        writeln!(out, "{}{}{}", indent, branch, node_line(child, highlight)).unwrap();
+        render_children(&child.children, &next_indent, highlight, out);
+    }
+}
+
+/// Render a list of issues grouped by parent.
+///
+/// Issues whose parent is part of the list are shown below it. Issues whose
+/// parent is not in the list are grouped below a dimmed parent header.
+pub fn render_issue_list_tree(issues: &[Issue]) -> String {
+    let in_list = |id: &cynic::Id| issues.iter().any(|i| i.id == *id);
+
+    let mut out = String::new();
+    let mut outside_parents = Vec::<&cynic::Id>::new();
+    for issue in issues {
+        match &issue.parent {
+            None => {
+                writeln!(out, "{}", issue_line(issue)).unwrap();
+                render_list_children(issues, &issue.id, "", &mut out);
+            }
+            Some(parent) if !in_list(&parent.id) => {
+                if !outside_parents.contains(&&parent.id) {
+                    outside_parents.push(&parent.id);
+                    let header = format!("{} {}", parent.identifier, parent.title);
+                    writeln!(out, "{}", header.dimmed()).unwrap();
+                    render_list_children(issues, &parent.id, "", &mut out);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    out
+}
+
+fn render_list_children(issues: &[Issue], parent_id: &cynic::Id, indent: &str, out: &mut String) {
+    let children = issues
+        .iter()
+        .filter(|i| i.parent.as_ref().is_some_and(|p| p.id == *parent_id))
+        .collect::<Vec<_>>();
+    for (index, child) in children.iter().enumerate() {
+        let last = index + 1 == children.len();
+        let (branch, next_indent) = if last {
+            ("└── ", format!("{}    ", indent))
+        } else {
+            ("├── ", format!("{}│   ", indent))
+        };
+        writeln!(out, "{}{}{}", indent, branch, issue_line(child)).unwrap();
+        render_list_children(issues, &child.id, &next_indent, out);
+    }
+}
+
+fn issue_line(issue: &Issue) -> String {
+    let mut line = format!(
+        "{} {} {}",
+        issue.identifier.bold(),
+        issue.title,
+        format!("[{}]", issue.state.name).dimmed()
+    );
+    if let Some(assignee) = &issue.assignee {
+        write!(line, " {}", format!("@{}", assignee.display_name).dimmed()).unwrap();
+    }
+    line
+}
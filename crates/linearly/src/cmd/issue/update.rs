@@ -0,0 +1,553 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+use cynic::MutationBuilder;
+use futures::TryStreamExt;
+
+use linear_api::{
+    schema::{
+        issue_batch_update::{IssueBatchUpdate, IssueBatchUpdateVariables, MAX_BATCH_SIZE},
+        issue_list::{Issue, IssueList, IssueListVariables},
+        issue_update::{IssueUpdate, IssueUpdateInput, IssueUpdateVariables},
+        lookup::TeamRef,
+        Nullable, PaginationOrderBy, Uuid,
+    },
+    Client,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render::{self, Render},
+    resolve, util,
+};
+
+use super::{filter::IssueSelection, IssuePriority};
+
+/// Update fields of one or more issues.
+///
+/// Prints a before/after diff of the changed fields.
+/// Optional fields can be cleared by passing "none".
+///
+/// Multiple issues can be selected by passing several IDs, "-" to read IDs
+/// from stdin, or with --filter. A preview of the selected issues is shown
+/// and confirmation is required unless --yes is given.
+#[derive(clap::Parser)]
+pub struct CmdIssueUpdate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Issue IDs (eg: MYTEAM-123).
+    ///
+    /// Use "-" to read whitespace separated IDs from stdin.
+    pub issue_ids: Vec<String>,
+
+    /// Select issues with a filter query instead of IDs.
+    /// Uses the same syntax as `issue list --filter`.
+    /// Can be specified multiple times.
+    #[clap(long = "filter", conflicts_with = "issue_ids")]
+    pub filters: Vec<String>,
+
+    /// Only show the issues that would be updated.
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Update multiple issues without asking for confirmation.
+    #[clap(long, short)]
+    pub yes: bool,
+
+    /// New issue title.
+    #[clap(long)]
+    pub title: Option<String>,
+
+    /// Name of the workflow state (eg: "In Progress").
+    #[clap(long, short)]
+    pub state: Option<String>,
+
+    /// Name of the assigned user.
+    ///
+    /// Use "me" as an alias for yourself, or "none" to unassign.
+    #[clap(long, short)]
+    pub assignee: Option<String>,
+
+    /// Add a label. Can be specified multiple times.
+    #[clap(long = "add-label", short = 'l')]
+    pub add_labels: Vec<String>,
+
+    /// Remove a label. Can be specified multiple times.
+    #[clap(long = "remove-label")]
+    pub remove_labels: Vec<String>,
+
+    /// Priority: none, urgent, high, medium, low (or 0-4).
+    #[clap(long, short)]
+    pub priority: Option<IssuePriority>,
+
+    /// Estimate in points, or "none".
+    #[clap(long, short)]
+    pub estimate: Option<String>,
+
+    /// Due date (YYYY-MM-DD), or "none".
+    #[clap(long)]
+    pub due_date: Option<String>,
+
+    /// Project name or slug ID, or "none".
+    #[clap(long)]
+    pub project: Option<String>,
+
+    /// Cycle number, "current", "next", "previous" or "none".
+    #[clap(long)]
+    pub cycle: Option<String>,
+
+    /// Parent issue ID (eg: MYTEAM-123), or "none".
+    #[clap(long)]
+    pub parent: Option<String>,
+}
+
+impl CliCommand for CmdIssueUpdate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        if !self.has_changes() {
+            bail!("nothing to update: specify at least one field to change (see --help)");
+        }
+
+        let client = self.common.client()?;
+        let issues = self.select_issues(&client).await?;
+        if issues.is_empty() {
+            eprintln!("No matching issues.");
+            return Ok(());
+        }
+        let bulk = issues.len() > 1 || !self.filters.is_empty();
+        let changes = self.resolve_changes(&client, &issues).await?;
+
+        if bulk || self.dry_run {
+            eprintln!("Issues to update:");
+            let columns = Issue::default_list_fields();
+            eprintln!("{}", Issue::render_list_table(&issues, &columns));
+        }
+        if self.dry_run {
+            return Ok(());
+        }
+        if bulk && !self.yes {
+            if !util::is_interactive() {
+                bail!(
+                    "refusing to update {} issues without confirmation: pass --yes",
+                    issues.len()
+                );
+            }
+            if !util::confirm(&format!("Update {} issues?", issues.len()))? {
+                eprintln!("Aborted.");
+                return Ok(());
+            }
+        }
+
+        let updated = if bulk {
+            self.batch_update(&client, &changes, &issues).await?
+        } else {
+            let before = &issues[0];
+            let payload = client
+                .query(IssueUpdate::build(IssueUpdateVariables {
+                    id: before.id.clone().into_inner(),
+                    input: self.input_for(&changes, before),
+                }))
+                .await
+                .with_context(|| format!("could not update issue {}", before.identifier))?
+                .issue_update;
+            match payload.issue {
+                Some(issue) if payload.success => vec![issue],
+                _ => bail!("issue update failed"),
+            }
+        };
+
+        let pairs = issues
+            .iter()
+            .filter_map(|before| {
+                let after = updated.iter().find(|after| after.id == before.id)?;
+                Some((before, after))
+            })
+            .collect::<Vec<_>>();
+
+        match self.format.resolve() {
+            Format::Table => {
+                if bulk {
+                    eprintln!("Updated {} issues", updated.len());
+                } else {
+                    let issue = &updated[0];
+                    eprintln!("Updated issue {}: {}", issue.identifier, issue.title);
+                }
+                match render_diff(&pairs, bulk) {
+                    Some(diff) => println!("{}", diff),
+                    None => eprintln!("No changes."),
+                }
+            }
+            format if bulk => println!("{}", render::render_value(&updated, format)?),
+            format => println!("{}", render::render_value(&updated[0], format)?),
+        }
+
+        Ok(())
+    }
+}
+
+/// Changes with names resolved to IDs.
+struct ResolvedChanges {
+    /// Fields that are the same for every issue.
+    base: IssueUpdateInput,
+    /// Team specific fields, by team ID.
+    teams: HashMap<cynic::Id, TeamChanges>,
+}
+
+/// Changes that reference entities scoped to a team.
+#[derive(Default)]
+struct TeamChanges {
+    state_id: Option<String>,
+    cycle_id: Option<Nullable<String>>,
+    add_label_ids: Vec<String>,
+}
+
+impl CmdIssueUpdate {
+    fn has_changes(&self) -> bool {
+        self.title.is_some()
+            || self.state.is_some()
+            || self.assignee.is_some()
+            || !self.add_labels.is_empty()
+            || !self.remove_labels.is_empty()
+            || self.priority.is_some()
+            || self.estimate.is_some()
+            || self.due_date.is_some()
+            || self.project.is_some()
+            || self.cycle.is_some()
+            || self.parent.is_some()
+    }
+
+    /// Load the issues selected by IDs or filters.
+    async fn select_issues(&self, client: &Client) -> Result<Vec<Issue>, anyhow::Error> {
+        if !self.filters.is_empty() {
+            let selection = IssueSelection {
+                queries: self.filters.clone(),
+                ..Default::default()
+            };
+            let vars = IssueListVariables {
+                first: 0,
+                after: None,
+                order: PaginationOrderBy::UpdatedAt,
+                sort: None,
+                filter: selection.to_filter(client).await?,
+            };
+            let issues = client
+                .paginate::<IssueList>(vars, None)
+                .try_collect()
+                .await?;
+            return Ok(issues);
+        }
+
+        let ids = util::issue_ids(&self.issue_ids)?;
+        if ids.is_empty() {
+            bail!("no issues selected: pass issue IDs, \"-\" to read IDs from stdin, or --filter");
+        }
+
+        let mut issues = Vec::<Issue>::new();
+        for id in &ids {
+            let issue = resolve::issue(client, id).await?;
+            if !issues.iter().any(|i| i.id == issue.id) {
+                issues.push(issue);
+            }
+        }
+        Ok(issues)
+    }
+
+    async fn resolve_changes(
+        &self,
+        client: &Client,
+        issues: &[Issue],
+    ) -> Result<ResolvedChanges, anyhow::Error> {
+        for name in &self.remove_labels {
+            let found = issues
+                .iter()
+                .flat_map(|i| &i.labels.nodes)
+                .any(|l| l.name.eq_ignore_ascii_case(name));
+            if !found {
+                bail!("no selected issue has the label '{}'", name);
+            }
+        }
+
+        let mut base = IssueUpdateInput {
+            title: self.title.clone(),
+            priority: self.priority.map(IssuePriority::value),
+            ..Default::default()
+        };
+
+        base.assignee_id = match util::clearable(&self.assignee) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(name)) => Some(Nullable::Value(
+                resolve::user(client, name).await?.id.into_inner(),
+            )),
+        };
+
+        base.estimate = match util::clearable(&self.estimate) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(value)) => Some(Nullable::Value(
+                value
+                    .parse()
+                    .with_context(|| format!("invalid estimate '{}'", value))?,
+            )),
+        };
+
+        base.due_date = match util::clearable(&self.due_date) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(value)) => Some(Nullable::Value(util::parse_date(value)?)),
+        };
+
+        base.project_id = match util::clearable(&self.project) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(name)) => Some(Nullable::Value(
+                resolve::project(client, name).await?.id.into_inner(),
+            )),
+        };
+
+        base.parent_id = match util::clearable(&self.parent) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(id)) => Some(Nullable::Value(
+                resolve::issue(client, id).await?.id.into_inner(),
+            )),
+        };
+
+        let mut teams = HashMap::new();
+        for issue in issues {
+            if !teams.contains_key(&issue.team.id) {
+                let changes = self.resolve_team_changes(client, &issue.team).await?;
+                teams.insert(issue.team.id.clone(), changes);
+            }
+        }
+
+        Ok(ResolvedChanges { base, teams })
+    }
+
+    async fn resolve_team_changes(
+        &self,
+        client: &Client,
+        team: &TeamRef,
+    ) -> Result<TeamChanges, anyhow::Error> {
+        let mut changes = TeamChanges::default();
+
+        if let Some(name) = &self.state {
+            let state = resolve::workflow_state(client, team, name).await?;
+            changes.state_id = Some(state.id.into_inner());
+        }
+
+        changes.cycle_id = match util::clearable(&self.cycle) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(spec)) => Some(Nullable::Value(
+                resolve::cycle(client, team, spec).await?.id.into_inner(),
+            )),
+        };
+
+        changes.add_label_ids = resolve::labels(client, Some(team), &self.add_labels)
+            .await?
+            .into_iter()
+            .map(|l| l.id.into_inner())
+            .collect();
+
+        Ok(changes)
+    }
+
+    /// Build the update input for a single issue.
+    fn input_for(&self, changes: &ResolvedChanges, issue: &Issue) -> IssueUpdateInput {
+        let mut input = changes.base.clone();
+        let Some(team) = changes.teams.get(&issue.team.id) else {
+            return input;
+        };
+        input.state_id.clone_from(&team.state_id);
+        input.cycle_id.clone_from(&team.cycle_id);
+
+        if !self.add_labels.is_empty() || !self.remove_labels.is_empty() {
+            let mut label_ids = issue
+                .labels
+                .nodes
+                .iter()
+                .filter(|l| {
+                    !self
+                        .remove_labels
+                        .iter()
+                        .any(|r| r.eq_ignore_ascii_case(&l.name))
+                })
+                .map(|l| l.id.clone().into_inner())
+                .collect::<Vec<_>>();
+            for id in &team.add_label_ids {
+                if !label_ids.contains(id) {
+                    label_ids.push(id.clone());
+                }
+            }
+            input.label_ids = Some(label_ids);
+        }
+
+        input
+    }
+
+    /// Update the issues with `issueBatchUpdate`.
+    ///
+    /// Issues that end up with the same input (eg: same team and labels) are
+    /// updated together.
+    async fn batch_update(
+        &self,
+        client: &Client,
+        changes: &ResolvedChanges,
+        issues: &[Issue],
+    ) -> Result<Vec<Issue>, anyhow::Error> {
+        let mut groups = Vec::<(String, IssueUpdateInput, Vec<Uuid>)>::new();
+        for issue in issues {
+            let input = self.input_for(changes, issue);
+            let key = serde_json::to_string(&input)?;
+            let id = Uuid(issue.id.inner().to_string());
+            match groups.iter_mut().find(|(k, _, _)| *k == key) {
+                Some((_, _, ids)) => ids.push(id),
+                None => groups.push((key, input, vec![id])),
+            }
+        }
+
+        let mut updated = Vec::with_capacity(issues.len());
+        for (_, input, ids) in groups {
+            updated.extend(batch_update(client, &ids, &input).await?);
+        }
+        Ok(updated)
+    }
+}
+
+/// Apply the same update to issues with `issueBatchUpdate`, in batches of at
+/// most [`MAX_BATCH_SIZE`].
+pub async fn batch_update(
+    client: &Client,
+    ids: &[Uuid],
+    input: &IssueUpdateInput,
+) -> Result<Vec<Issue>, anyhow::Error> {
+    let mut updated = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(MAX_BATCH_SIZE) {
+        let payload = client
+            .query(IssueBatchUpdate::build(IssueBatchUpdateVariables {
+                ids: chunk.to_vec(),
+                input: input.clone(),
+            }))
+            .await
+            .context("could not update issues")?
+            .issue_batch_update;
+        if !payload.success {
+            bail!("issue batch update failed");
+        }
+        updated.extend(payload.issues);
+    }
+    Ok(updated)
+}
+
+/// Fields shown in the before/after diff.
+fn diff_fields(issue: &Issue) -> Vec<(&'static str, String)> {
+    vec![
+        ("Title", issue.title.clone()),
+        ("State", issue.state.name.clone()),
+        (
+            "Assignee",
+            issue
+                .assignee
+                .as_ref()
+                .map(|a| a.display_name.clone())
+                .unwrap_or_default(),
+        ),
+        (
+            "Labels",
+            issue
+                .labels
+                .nodes
+                .iter()
+                .map(|l| l.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        ("Priority", issue.priority_label.clone()),
+        (
+            "Estimate",
+            issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+        ),
+        (
+            "Due date",
+            issue
+                .due_date
+                .as_ref()
+                .map(|d| d.0.clone())
+                .unwrap_or_default(),
+        ),
+        (
+            "Project",
+            issue
+                .project
+                .as_ref()
+                .map(|p| p.name.clone())
+                .unwrap_or_default(),
+        ),
+        (
+            "Cycle",
+            issue
+                .cycle
+                .as_ref()
+                .map(|c| match &c.name {
+                    Some(name) => format!("{} ({})", c.number, name),
+                    None => c.number.to_string(),
+                })
+                .unwrap_or_default(),
+        ),
+        (
+            "Parent",
+            issue
+                .parent
+                .as_ref()
+                .map(|p| p.identifier.clone())
+                .unwrap_or_default(),
+        ),
+    ]
+}
+
+/// Render a table of the fields that differ between the issue versions.
+///
+/// Returns `None` if nothing changed.
+fn render_diff(pairs: &[(&Issue, &Issue)], show_issue: bool) -> Option<String> {
+    let mut rows = Vec::new();
+    for (before, after) in pairs {
+        let changed = diff_fields(before)
+            .into_iter()
+            .zip(diff_fields(after))
+            .filter(|((_, old), (_, new))| old != new);
+        for ((field, old), (_, new)) in changed {
+            let mut row = Vec::new();
+            if show_issue {
+                row.push(Cell::new(&after.identifier));
+            }
+            row.push(Cell::new(field).add_attribute(Attribute::Bold));
+            row.push(Cell::new(old).fg(Color::Red));
+            row.push(Cell::new(new).fg(Color::Green));
+            rows.push(row);
+        }
+    }
+
+    if rows.is_empty() {
+        return None;
+    }
+
+    let mut header = vec!["Field", "Before", "After"];
+    if show_issue {
+        header.insert(0, "Issue");
+    }
+
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .load_preset(comfy_table::presets::UTF8_HORIZONTAL_ONLY)
+        .set_header(header)
+        .add_rows(rows);
+    Some(table.to_string())
+}
@@ -1,23 +1,20 @@
-use std::process::Stdio;
+use std::{fmt::Write, process::Stdio};
 
 use anyhow::Context;
+use colored::Colorize;
 use cynic::QueryBuilder;
 
-use linear_api::schema::{
-    issue_get::{IssueGet, IssueGetVariables},
-    issue_list::Issue,
-};
+use linear_api::schema::issue_view::{IssueDetail, IssueSummary, IssueView, IssueViewVariables};
 
 use crate::{
-    cmd::{CliCommand, CommonArgs, Format},
-    render::Render,
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render::{self, format_date, markdown},
 };
 
-/// Checkout or create the GIT branch for an issue.
+/// Show the details of an issue.
 ///
-/// Will use the branch name generated by Linear.
-/// Requires the current directory to be inside a Git repository.
-// TODO: check if a PR is already open for the issue, and use the existing branch.
+/// Includes the description, sub-issues, relations, attachments and the most
+/// recent comments.
 #[derive(clap::Parser)]
 pub struct CmdIssueView {
     #[clap(flatten)]
@@ -26,10 +23,17 @@ pub struct CmdIssueView {
     #[clap(long, short, default_value = "table")]
     pub format: Format,
 
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
     /// Open the issue in the browser.
     #[clap(long, short)]
     pub web: bool,
 
+    /// Number of recent comments to show.
+    #[clap(long, default_value = "5")]
+    pub comments: usize,
+
     /// Issue ID (eg: MYTEAM-123)
     pub issue_id: String,
 }
@@ -38,24 +42,30 @@ impl CliCommand for CmdIssueView {
     type Output = ();
 
     async fn run(self) -> Result<(), anyhow::Error> {
-        // Load the issue to determine the branch name.
-        let vars = IssueGetVariables {
+        let template = self.template.compile()?;
+        let vars = IssueViewVariables {
             id: self.issue_id.clone(),
         };
-        let op = IssueGet::build(vars);
-
-        let issue = self
-            .common
-            .client()?
-            .run(op)
-            .await?
-            .data
-            .with_context(|| format!("issue {} not found", self.issue_id))?
+        let op = IssueView::build(vars);
+
+        let client = self.common.client()?;
+        let issue = client
+            .query(op)
+            .await
+            .with_context(|| format!("could not load issue {}", self.issue_id))?
             .issue;
 
-        let fields = Issue::default_detail_fields();
-        let out = Issue::render_detail_table(&issue, &fields);
-        println!("{}", out);
+        if let Some(template) = &template {
+            println!("{}", template.render(&issue)?);
+        } else {
+            match self.format.resolve() {
+                Format::Table => {
+                    markdown::load_team_keys(&client).await?;
+                    print!("{}", render_issue(&issue, self.comments));
+                }
+                format => println!("{}", render::render_value(&issue, format)?),
+            }
+        }
 
         if self.web {
             eprintln!("Opening URL {} in the browser...", issue.url);
@@ -68,3 +78,225 @@ impl CliCommand for CmdIssueView {
         Ok(())
     }
 }
+
+fn format_summary(issue: &IssueSummary) -> String {
+    format!(
+        "{} {} {}",
+        issue.identifier.bold(),
+        format!("[{}]", issue.state.name).dimmed(),
+        issue.title
+    )
+}
+
+fn section(out: &mut String, title: &str) {
+    writeln!(out).unwrap();
+    writeln!(out, "{}", title.bold().underline()).unwrap();
+}
+
+/// Render all details of an issue as a multi-section text view.
+fn render_issue(issue: &IssueDetail, max_comments: usize) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "{} {}", issue.identifier.bold(), issue.title.bold()).unwrap();
+    writeln!(out, "{}", issue.url.dimmed()).unwrap();
+
+    let mut fields: Vec<(&str, String)> = vec![
+        ("State", issue.state.name.clone()),
+        ("Priority", issue.priority_label.clone()),
+        ("Team", format!("{} ({})", issue.team.name, issue.team.key)),
+    ];
+    if let Some(user) = &issue.assignee {
+        fields.push(("Assignee", user.display_name.clone()));
+    }
+    if let Some(user) = &issue.creator {
+        fields.push(("Creator", user.display_name.clone()));
+    }
+    if !issue.labels.nodes.is_empty() {
+        let labels = issue
+            .labels
+            .nodes
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        fields.push(("Labels", labels));
+    }
+    if let Some(project) = &issue.project {
+        fields.push(("Project", project.name.clone()));
+    }
+    if let Some(milestone) = &issue.project_milestone {
+        fields.push(("Milestone", milestone.name.clone()));
+    }
+    if let Some(cycle) = &issue.cycle {
+        let name = match &cycle.name {
+            Some(name) => format!("{} ({})", cycle.number, name),
+            None => cycle.number.to_string(),
+        };
+        fields.push(("Cycle", name));
+    }
+    if let Some(estimate) = issue.estimate {
+        fields.push(("Estimate", estimate.to_string()));
+    }
+    if let Some(due) = &issue.due_date {
+        fields.push(("Due", due.0.clone()));
+    }
+    fields.push(("Created", format_date(&issue.created_at)));
+    fields.push(("Updated", format_date(&issue.updated_at)));
+    if let Some(at) = &issue.completed_at {
+        fields.push(("Completed", format_date(at)));
+    }
+    if let Some(at) = &issue.canceled_at {
+        fields.push(("Canceled", format_date(at)));
+    }
+    if !issue.subscribers.nodes.is_empty() {
+        let names = issue
+            .subscribers
+            .nodes
+            .iter()
+            .map(|u| u.display_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        fields.push(("Subscribers", names));
+    }
+    fields.push(("Branch", issue.branch_name.clone()));
+
+    writeln!(out).unwrap();
+    let width = fields
+        .iter()
+        .map(|(k, _)| k.len())
+        .max()
+        .unwrap_or_default();
+    for (key, value) in &fields {
+        writeln!(out, "{}  {}", format!("{:>width$}", key).dimmed(), value).unwrap();
+    }
+
+    section(&mut out, "Description");
+    match issue.description.as_deref().map(str::trim) {
+        Some(description) if !description.is_empty() => {
+            writeln!(out, "{}", markdown::render(description)).unwrap();
+        }
+        _ => writeln!(out, "{}", "No description.".dimmed()).unwrap(),
+    }
+
+    if issue.parent.is_some() || !issue.children.nodes.is_empty() {
+        section(&mut out, "Sub-issues");
+        if let Some(parent) = &issue.parent {
+            writeln!(
+                out,
+                "{} {} {}",
+                "parent".dimmed(),
+                parent.identifier.bold(),
+                parent.title
+            )
+            .unwrap();
+        }
+        for child in &issue.children.nodes {
+            writeln!(out, "  - {}", format_summary(child)).unwrap();
+        }
+    }
+
+    let relations = issue
+        .relations
+        .nodes
+        .iter()
+        .map(|r| (relation_label(&r.type_, false), &r.related_issue))
+        .chain(
+            issue
+                .inverse_relations
+                .nodes
+                .iter()
+                .map(|r| (relation_label(&r.type_, true), &r.issue)),
+        )
+        .collect::<Vec<_>>();
+    if !relations.is_empty() {
+        section(&mut out, "Relations");
+        let width = relations.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+        for (label, related) in relations {
+            writeln!(
+                out,
+                "{}  {}",
+                format!("{:>width$}", label).dimmed(),
+                format_summary(related),
+            )
+            .unwrap();
+        }
+    }
+
+    if !issue.attachments.nodes.is_empty() {
+        section(&mut out, "Attachments");
+        for attachment in &issue.attachments.nodes {
+            write!(out, "  - {}", attachment.title).unwrap();
+            if let Some(subtitle) = &attachment.subtitle {
+                write!(out, " ({})", subtitle).unwrap();
+            }
+            writeln!(out, "\n    {}", attachment.url.dimmed()).unwrap();
+        }
+    }
+
+    let comments = &issue.comments.nodes;
+    if max_comments > 0 && !comments.is_empty() {
+        let mut recent = comments.iter().collect::<Vec<_>>();
+        recent.sort_by(|a, b| a.created_at.0.cmp(&b.created_at.0));
+        let skipped = recent.len().saturating_sub(max_comments);
+        // Only the most recent comments are loaded, so the total is unknown
+        // if there are older ones.
+        let title = if issue.comments.page_info.has_previous_page {
+            format!("Recent comments ({})", recent.len() - skipped)
+        } else {
+            format!("Comments ({} of {})", recent.len() - skipped, recent.len())
+        };
+        section(&mut out, &title);
+        for comment in recent.into_iter().skip(skipped) {
+            let author = comment
+                .user
+                .as_ref()
+                .map(|u| u.display_name.as_str())
+                .unwrap_or("(unknown)");
+            let reply = if comment.parent.is_some() {
+                " (reply)"
+            } else {
+                ""
+            };
+            writeln!(
+                out,
+                "\n{}{} {}",
+                author.bold(),
+                reply,
+                format_date(&comment.created_at).dimmed()
+            )
+            .unwrap();
+            for line in markdown::render(&comment.body).lines() {
+                writeln!(out, "  {}", line).unwrap();
+            }
+        }
+        if skipped > 0 || issue.comments.page_info.has_previous_page {
+            writeln!(
+                out,
+                "\n{}",
+                format!(
+                    "Use `linear issue comment list {}` to see all comments.",
+                    issue.identifier
+                )
+                .dimmed()
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
+
+/// Human readable name of a relation type.
+///
+/// `inverse` relations point from another issue to this one.
+fn relation_label(kind: &str, inverse: bool) -> String {
+    match (kind, inverse) {
+        ("blocks", false) => "blocks".to_string(),
+        ("blocks", true) => "blocked by".to_string(),
+        ("duplicate", false) => "duplicate of".to_string(),
+        ("duplicate", true) => "duplicated by".to_string(),
+        ("related", _) => "related to".to_string(),
+        ("similar", _) => "similar to".to_string(),
+        (other, _) => other.to_string(),
+    }
+}
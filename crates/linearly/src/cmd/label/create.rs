@@ -0,0 +1,94 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::labels::{
+    IssueLabelCreate, IssueLabelCreateInput, IssueLabelCreateVariables,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve,
+};
+
+/// Create a new label.
+///
+/// Labels are created in the workspace unless --team is given.
+/// A label becomes a group once other labels use it as --parent.
+#[derive(clap::Parser)]
+pub struct CmdLabelCreate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Label name.
+    pub name: String,
+
+    /// Create the label for this team only.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+
+    /// Color as "#rrggbb".
+    #[clap(long, short)]
+    pub color: Option<String>,
+
+    /// Label description.
+    #[clap(long, short)]
+    pub description: Option<String>,
+
+    /// Name of the label group to add the label to.
+    #[clap(long, short)]
+    pub parent: Option<String>,
+}
+
+impl CliCommand for CmdLabelCreate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        let team = match &self.team {
+            Some(key) => Some(resolve::team(&client, key).await?),
+            None => None,
+        };
+        let parent_id = match &self.parent {
+            Some(name) => Some(
+                resolve::label(&client, team.as_ref(), name)
+                    .await?
+                    .id
+                    .into_inner(),
+            ),
+            None => None,
+        };
+        if let Some(color) = &self.color {
+            super::check_color(color)?;
+        }
+
+        let input = IssueLabelCreateInput {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            color: self.color.clone(),
+            parent_id,
+            team_id: team.map(|t| t.id.into_inner()),
+        };
+
+        let payload = client
+            .query(IssueLabelCreate::build(IssueLabelCreateVariables { input }))
+            .await
+            .with_context(|| format!("could not create label {}", self.name))?
+            .issue_label_create;
+        if !payload.success {
+            bail!("label creation failed");
+        }
+
+        let label = payload.issue_label;
+        match self.format.resolve() {
+            Format::Table => eprintln!("Created label {}", super::render_tree(&[label]).trim_end()),
+            format => println!("{}", render::render_value(&label, format)?),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::labels::{IssueLabelDelete, IssueLabelIdVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs},
+    resolve, util,
+};
+
+/// Delete a label.
+///
+/// The label is removed from all issues.
+#[derive(clap::Parser)]
+pub struct CmdLabelDelete {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Delete without asking for confirmation.
+    #[clap(long, short)]
+    pub yes: bool,
+
+    /// Label name, or "Group/Label" for labels inside a group.
+    pub label: String,
+
+    /// Prefer the label of this team over a workspace label of the same name.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+}
+
+impl CliCommand for CmdLabelDelete {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let team = match &self.team {
+            Some(key) => Some(resolve::team(&client, key).await?),
+            None => None,
+        };
+        let label = resolve::label(&client, team.as_ref(), &self.label).await?;
+
+        if !self.yes {
+            if !util::is_interactive() {
+                bail!("refusing to delete the label without confirmation: pass --yes");
+            }
+            let scope = match &label.team {
+                Some(team) => format!("team {}", team.key),
+                None => "the workspace".to_string(),
+            };
+            if !util::confirm(&format!("Delete label '{}' of {}?", label.name, scope))? {
+                eprintln!("Aborted.");
+                return Ok(());
+            }
+        }
+
+        let payload = client
+            .query(IssueLabelDelete::build(IssueLabelIdVariables {
+                id: label.id.clone().into_inner(),
+            }))
+            .await
+            .with_context(|| format!("could not delete label {}", label.name))?
+            .issue_label_delete;
+        if !payload.success {
+            bail!("label deletion failed");
+        }
+
+        eprintln!("Deleted label {}", label.name);
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use futures::TryStreamExt;
+
+use linear_api::schema::{
+    issue_list::{Idcomparator, IssueLabelFilter, NullableTeamFilter},
+    labels::{Label, LabelList, LabelListVariables},
+};
+
+use crate::{
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{self, Render},
+    resolve,
+};
+
+use super::LabelTableColumns;
+
+/// List labels, with label groups shown as a tree.
+///
+/// A table is shown instead when columns are selected with --columns or
+/// --wide.
+#[derive(clap::Parser)]
+pub struct CmdLabelList {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<LabelTableColumns>,
+
+    /// Only list labels of this team, and workspace labels.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+}
+
+impl CliCommand for CmdLabelList {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+
+        let filter = match &self.team {
+            Some(key) => {
+                let team = resolve::team(&client, key).await?;
+                Some(IssueLabelFilter {
+                    or: Some(vec![
+                        IssueLabelFilter {
+                            team: Some(Box::new(NullableTeamFilter {
+                                id: Some(Idcomparator {
+                                    eq: Some(team.id.clone()),
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        },
+                        IssueLabelFilter {
+                            team: Some(Box::new(NullableTeamFilter {
+                                null: Some(true),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                })
+            }
+            None => None,
+        };
+
+        let vars = LabelListVariables {
+            filter,
+            first: 0,
+            after: None,
+        };
+        let labels = client.paginate::<LabelList>(vars, None);
+        if let Some(template) = &template {
+            return template.print_stream(labels).await;
+        }
+        if let Format::Ndjson = self.format {
+            return render::stream_ndjson(labels).await;
+        }
+        let labels: Vec<Label> = labels.try_collect().await?;
+
+        match self.format.resolve() {
+            Format::Table if !self.columns.is_set() => print!("{}", super::render_tree(&labels)),
+            format => {
+                let columns = self.columns.resolve::<Label>("label")?;
+                println!("{}", Label::render_list(&labels, &columns, format)?);
+            }
+        }
+
+        Ok(())
+    }
+}
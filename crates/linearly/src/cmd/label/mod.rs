@@ -0,0 +1,192 @@
+use std::fmt::Write;
+
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color};
+
+use linear_api::schema::labels::Label;
+
+use crate::render::{self, Render};
+
+use super::CliCommand;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+#[derive(clap::Subcommand)]
+pub enum CmdLabel {
+    #[clap(alias = "ls")]
+    List(list::CmdLabelList),
+    #[clap(alias = "new")]
+    Create(create::CmdLabelCreate),
+    Update(update::CmdLabelUpdate),
+    #[clap(alias = "rm")]
+    Delete(delete::CmdLabelDelete),
+}
+
+impl CliCommand for CmdLabel {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        match self {
+            CmdLabel::List(c) => c.run().await,
+            CmdLabel::Create(c) => c.run().await,
+            CmdLabel::Update(c) => c.run().await,
+            CmdLabel::Delete(c) => c.run().await,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum LabelTableColumns {
+    Name,
+    Id,
+    Description,
+    Color,
+    Group,
+    Parent,
+    Team,
+}
+
+impl Render for Label {
+    type Fields = LabelTableColumns;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            LabelTableColumns::Name,
+            LabelTableColumns::Parent,
+            LabelTableColumns::Team,
+            LabelTableColumns::Color,
+            LabelTableColumns::Description,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            LabelTableColumns::Name,
+            LabelTableColumns::Id,
+            LabelTableColumns::Group,
+            LabelTableColumns::Parent,
+            LabelTableColumns::Team,
+            LabelTableColumns::Color,
+            LabelTableColumns::Description,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        Self::default_list_fields()
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            LabelTableColumns::Name => Cell::new("Name").add_attribute(Attribute::Bold),
+            LabelTableColumns::Id => Cell::new("ID"),
+            LabelTableColumns::Description => Cell::new("Description"),
+            LabelTableColumns::Color => Cell::new("Color"),
+            LabelTableColumns::Group => Cell::new("Group"),
+            LabelTableColumns::Parent => Cell::new("Parent"),
+            LabelTableColumns::Team => Cell::new("Team"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        match field {
+            LabelTableColumns::Name => Cell::new(&item.name),
+            LabelTableColumns::Id => Cell::new(item.id.inner()),
+            LabelTableColumns::Description => {
+                Cell::new(item.description.as_deref().unwrap_or_default())
+            }
+            LabelTableColumns::Color => {
+                let cell = Cell::new(&item.color);
+                match render::parse_color(&item.color) {
+                    Some((r, g, b)) => cell.fg(Color::Rgb { r, g, b }),
+                    None => cell,
+                }
+            }
+            LabelTableColumns::Group => Cell::new(if item.is_group { "yes" } else { "" }),
+            LabelTableColumns::Parent => Cell::new(
+                item.parent
+                    .as_ref()
+                    .map(|p| p.name.as_str())
+                    .unwrap_or_default(),
+            ),
+            LabelTableColumns::Team => Cell::new(
+                item.team
+                    .as_ref()
+                    .map(|t| t.key.as_str())
+                    .unwrap_or("workspace"),
+            ),
+        }
+    }
+}
+
+/// Render labels as a tree, with the labels of each group below the group.
+///
+/// Labels are sorted by name, with workspace labels before team labels.
+pub fn render_tree(labels: &[Label]) -> String {
+    let mut roots = labels
+        .iter()
+        .filter(|l| {
+            l.parent
+                .as_ref()
+                .is_none_or(|p| !labels.iter().any(|g| g.id == p.id))
+        })
+        .collect::<Vec<_>>();
+    sort_labels(&mut roots);
+
+    let mut out = String::new();
+    for label in roots {
+        writeln!(out, "{}", label_line(label)).unwrap();
+
+        let mut children = labels
+            .iter()
+            .filter(|l| l.parent.as_ref().is_some_and(|p| p.id == label.id))
+            .collect::<Vec<_>>();
+        sort_labels(&mut children);
+        let count = children.len();
+        for (index, child) in children.into_iter().enumerate() {
+            let branch = if index + 1 == count {
+                "└─"
+            } else {
+                "├─"
+            };
+            writeln!(out, "{} {}", branch.dimmed(), label_line(child)).unwrap();
+        }
+    }
+    out
+}
+
+fn sort_labels(labels: &mut [&Label]) {
+    labels.sort_by(|a, b| {
+        let a_team = a.team.as_ref().map(|t| t.key.as_str());
+        let b_team = b.team.as_ref().map(|t| t.key.as_str());
+        a_team
+            .cmp(&b_team)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+}
+
+fn label_line(label: &Label) -> String {
+    let mut line = format!("{} {}", render::color_swatch(&label.color), label.name);
+    if label.is_group {
+        line = format!("{} {}", line.bold(), "(group)".dimmed());
+    }
+    let scope = match &label.team {
+        Some(team) => team.key.clone(),
+        None => "workspace".to_string(),
+    };
+    write!(line, " {}", format!("[{}]", scope).dimmed()).unwrap();
+    if let Some(description) = label.description.as_deref().filter(|d| !d.is_empty()) {
+        write!(line, " {}", description.dimmed()).unwrap();
+    }
+    line
+}
+
+/// Check that a color is given as "#rrggbb".
+fn check_color(color: &str) -> Result<(), anyhow::Error> {
+    if render::parse_color(color).is_none() {
+        anyhow::bail!("invalid color '{}': expected the form #rrggbb", color);
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::{
+    labels::{IssueLabelUpdate, IssueLabelUpdateInput, IssueLabelUpdateVariables},
+    Nullable,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format},
+    render, resolve, util,
+};
+
+/// Update a label.
+#[derive(clap::Parser)]
+pub struct CmdLabelUpdate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Label name, or "Group/Label" for labels inside a group.
+    pub label: String,
+
+    /// Prefer the label of this team over a workspace label of the same name.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+
+    /// New name.
+    #[clap(long, short)]
+    pub name: Option<String>,
+
+    /// New color as "#rrggbb".
+    #[clap(long, short)]
+    pub color: Option<String>,
+
+    /// New description.
+    #[clap(long, short)]
+    pub description: Option<String>,
+
+    /// Name of the label group to move the label to, or "none".
+    #[clap(long, short)]
+    pub parent: Option<String>,
+}
+
+impl CliCommand for CmdLabelUpdate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        if self.name.is_none()
+            && self.color.is_none()
+            && self.description.is_none()
+            && self.parent.is_none()
+        {
+            bail!("nothing to update: specify at least one field to change (see --help)");
+        }
+        if let Some(color) = &self.color {
+            super::check_color(color)?;
+        }
+
+        let client = self.common.client()?;
+        let team = match &self.team {
+            Some(key) => Some(resolve::team(&client, key).await?),
+            None => None,
+        };
+        let label = resolve::label(&client, team.as_ref(), &self.label).await?;
+
+        let parent_id = match util::clearable(&self.parent) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(name)) => Some(Nullable::Value(
+                resolve::label(&client, label.team.as_ref().or(team.as_ref()), name)
+                    .await?
+                    .id
+                    .into_inner(),
+            )),
+        };
+
+        let input = IssueLabelUpdateInput {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            color: self.color.clone(),
+            parent_id,
+        };
+
+        let payload = client
+            .query(IssueLabelUpdate::build(IssueLabelUpdateVariables {
+                id: label.id.into_inner(),
+                input,
+            }))
+            .await
+            .with_context(|| format!("could not update label {}", label.name))?
+            .issue_label_update;
+        if !payload.success {
+            bail!("label update failed");
+        }
+
+        let label = payload.issue_label;
+        match self.format.resolve() {
+            Format::Table => eprintln!("Updated label {}", super::render_tree(&[label]).trim_end()),
+            format => println!("{}", render::render_value(&label, format)?),
+        }
+
+        Ok(())
+    }
+}
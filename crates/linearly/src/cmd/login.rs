@@ -48,9 +48,8 @@ impl CliCommand for CmdLogin {
         let client = Client::new_default(token.clone());
 
         let viewer = client
-            .run(linear_api::schema::viewer::QueryViewer::build(()))
-            .await?
-            .data
+            .query(linear_api::schema::viewer::QueryViewer::build(()))
+            .await
             .context("could not retrieve current user")?
             .viewer;
 
@@ -1,30 +1,60 @@
-use crate::config::UserConfig;
+use linear_api::retry::RetryPolicy;
 
+use anyhow::bail;
+
+use crate::{
+    config::UserConfig,
+    render::{self, template::Template, Render},
+};
+
+pub mod cycle;
+pub mod inbox;
 pub mod issue;
+pub mod label;
 pub mod login;
 pub mod project;
+pub mod search;
 pub mod team;
 
 #[derive(clap::Parser)]
 pub struct Args {
     #[clap(subcommand)]
     cmd: Cmd,
+
+    /// Apply a jq filter to the JSON output, eg: '.[] | select(.priority > 2) | .identifier'.
+    ///
+    /// Tables are printed as JSON instead. YAML, CSV, TSV and Markdown output
+    /// show the filter results.
+    #[clap(long, global = true)]
+    query: Option<String>,
+
+    /// Print strings from --query without quotes.
+    #[clap(long, global = true, requires = "query")]
+    raw_output: bool,
 }
 
 impl CliCommand for Args {
     type Output = ();
 
     async fn run(self) -> Result<(), anyhow::Error> {
+        if let Some(query) = &self.query {
+            render::query::activate(query, self.raw_output)?;
+        }
         match self.cmd {
             Cmd::Project(c) => c.run().await,
             Cmd::Team(c) => c.run().await,
             Cmd::Issue(c) => c.run().await,
+            Cmd::Cycle(c) => c.run().await,
+            Cmd::Label(c) => c.run().await,
+            Cmd::Search(c) => c.run().await,
+            Cmd::Inbox(c) => c.run().await,
             Cmd::Login(c) => c.run().await,
         }
     }
 }
 
 #[derive(clap::Subcommand)]
+#[allow(clippy::large_enum_variant)]
 pub enum Cmd {
     #[clap(subcommand)]
     Project(project::CmdProject),
@@ -32,6 +62,12 @@ pub enum Cmd {
     Team(team::CmdTeam),
     #[clap(subcommand, alias = "i")]
     Issue(issue::CmdIssue),
+    #[clap(subcommand)]
+    Cycle(cycle::CmdCycle),
+    #[clap(subcommand)]
+    Label(label::CmdLabel),
+    Search(search::CmdSearch),
+    Inbox(inbox::CmdInbox),
 
     Login(login::CmdLogin),
 }
@@ -56,6 +92,10 @@ impl std::error::Error for NoTokenError {}
 pub struct CommonArgs {
     #[clap(long, env = "LINEAR_TOKEN")]
     pub api_token: Option<String>,
+
+    /// How often to retry rate limited or failed requests.
+    #[clap(long, env = "LINEAR_MAX_RETRIES", default_value = "3")]
+    pub max_retries: u32,
 }
 
 impl CommonArgs {
@@ -67,12 +107,117 @@ impl CommonArgs {
         } else {
             return Err(NoTokenError.into());
         };
-        Ok(linear_api::Client::new_default(token))
+        let retry = RetryPolicy {
+            max_retries: self.max_retries,
+            ..Default::default()
+        };
+        Ok(linear_api::Client::new_default(token).with_retry_policy(retry))
     }
 }
 
-#[derive(clap::ValueEnum, Clone)]
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
 pub enum Format {
     Table,
     Json,
+    /// One JSON object per line.
+    Ndjson,
+    Yaml,
+    Csv,
+    Tsv,
+    /// A GitHub flavored Markdown table.
+    Markdown,
+}
+
+impl Format {
+    /// The format to print in: `--query` filters the JSON output, so it
+    /// turns tables into JSON.
+    pub fn resolve(self) -> Self {
+        match self {
+            Format::Table if render::query::is_active() => Format::Json,
+            format => format,
+        }
+    }
+}
+
+/// Custom output with a template.
+#[derive(clap::Args)]
+pub struct TemplateArgs {
+    /// Print each item with a template instead of --format,
+    /// eg: '{{identifier}}\t{{title}}\t{{assignee.display_name}}'.
+    ///
+    /// Templates use the Jinja2 syntax (see https://docs.rs/minijinja) and
+    /// have access to the same fields as the JSON output.
+    /// The name of a template from the `[templates]` section of the config
+    /// file can be given instead.
+    #[clap(long, conflicts_with = "format")]
+    pub template: Option<String>,
+}
+
+impl TemplateArgs {
+    /// The selected template, looked up by name in the config file first.
+    pub fn compile(&self) -> Result<Option<Template>, anyhow::Error> {
+        let Some(template) = &self.template else {
+            return Ok(None);
+        };
+        let named = UserConfig::load()?.and_then(|mut c| c.templates.remove(template));
+        Template::new(named.as_deref().unwrap_or(template)).map(Some)
+    }
+}
+
+/// Table column selection for list commands.
+#[derive(clap::Args)]
+pub struct ColumnArgs<C: clap::ValueEnum + Clone + Send + Sync + 'static> {
+    /// Comma separated list of table columns to show.
+    ///
+    /// Defaults can be configured per resource in the `[columns]` section of
+    /// the config file (eg: `issue = ["key", "title", "priority"]`).
+    #[clap(long, value_delimiter = ',', conflicts_with = "wide")]
+    pub columns: Vec<C>,
+
+    /// Show more columns.
+    #[clap(long)]
+    pub wide: bool,
+}
+
+impl<C: clap::ValueEnum + Clone + Send + Sync + 'static> ColumnArgs<C> {
+    /// Whether columns were selected explicitly.
+    pub fn is_set(&self) -> bool {
+        !self.columns.is_empty() || self.wide
+    }
+
+    /// The columns to show: --columns, the --wide preset, the defaults for
+    /// `resource` from the config file, or the built-in defaults.
+    pub fn resolve<R: Render<Fields = C>>(&self, resource: &str) -> Result<Vec<C>, anyhow::Error> {
+        if !self.columns.is_empty() {
+            return Ok(self.columns.clone());
+        }
+        if self.wide {
+            return Ok(R::wide_list_fields());
+        }
+
+        let configured = UserConfig::load()?.and_then(|mut c| c.columns.remove(resource));
+        let Some(names) = configured else {
+            return Ok(R::default_list_fields());
+        };
+        names
+            .iter()
+            .map(|name| match C::from_str(name, true) {
+                Ok(column) => Ok(column),
+                Err(_) => {
+                    let valid = C::value_variants()
+                        .iter()
+                        .filter_map(|c| c.to_possible_value())
+                        .map(|v| v.get_name().to_string())
+                        .collect::<Vec<_>>();
+                    bail!(
+                        "invalid {} column '{}' in config file '{}' (valid: {})",
+                        resource,
+                        name,
+                        UserConfig::path().display(),
+                        valid.join(", ")
+                    )
+                }
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,80 @@
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::projects::{ProjectArchive, ProjectIdVariables, ProjectUnarchive};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs},
+    resolve,
+};
+
+/// Archive a project.
+#[derive(clap::Parser)]
+pub struct CmdProjectArchive {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Project name, slug ID or ID.
+    pub project: String,
+}
+
+impl CliCommand for CmdProjectArchive {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+        let project = resolve::project(&client, &self.project).await?;
+
+        let payload = client
+            .query(ProjectArchive::build(ProjectIdVariables {
+                id: project.id.into_inner(),
+            }))
+            .await
+            .with_context(|| format!("could not archive project {}", project.name))?
+            .project_archive;
+        if !payload.success {
+            bail!("archiving the project failed");
+        }
+
+        eprintln!("Archived project {}", project.name);
+        Ok(())
+    }
+}
+
+/// Restore an archived project.
+#[derive(clap::Parser)]
+pub struct CmdProjectUnarchive {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    /// Project slug ID or ID.
+    pub project: String,
+}
+
+impl CliCommand for CmdProjectUnarchive {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        // Archived projects are not returned by the project filter, so the
+        // identifier is passed on as is. The API accepts IDs and slug IDs.
+        let payload = self
+            .common
+            .client()?
+            .query(ProjectUnarchive::build(ProjectIdVariables {
+                id: self.project.clone(),
+            }))
+            .await
+            .with_context(|| format!("could not unarchive project {}", self.project))?
+            .project_unarchive;
+        if !payload.success {
+            bail!("unarchiving the project failed");
+        }
+
+        let name = payload
+            .entity
+            .map(|p| p.name)
+            .unwrap_or_else(|| self.project.clone());
+        eprintln!("Unarchived project {}", name);
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use cynic::MutationBuilder;
+
+use linear_api::schema::projects::{ProjectCreate, ProjectCreateInput, ProjectCreateVariables};
+
+use crate::{
+    cmd::{issue::IssuePriority, CliCommand, CommonArgs, Format},
+    render, resolve, util,
+};
+
+/// Create a new project.
+#[derive(clap::Parser)]
+pub struct CmdProjectCreate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Project name.
+    pub name: String,
+
+    /// Team of the project. Can be specified multiple times.
+    /// Expects the short team KEY.
+    #[clap(long = "team", short, required = true)]
+    pub teams: Vec<String>,
+
+    /// Short project description.
+    #[clap(long, short, conflicts_with = "description_file")]
+    pub description: Option<String>,
+
+    /// Read the description from a file.
+    /// Use "-" to read from stdin.
+    #[clap(long)]
+    pub description_file: Option<PathBuf>,
+
+    /// Name of the project lead.
+    ///
+    /// Use "me" as an alias for yourself.
+    #[clap(long)]
+    pub lead: Option<String>,
+
+    /// Name of a project member. Can be specified multiple times.
+    #[clap(long = "member")]
+    pub members: Vec<String>,
+
+    /// Project status (eg: "Planned").
+    #[clap(long, short)]
+    pub status: Option<String>,
+
+    /// Priority: none, urgent, high, medium, low (or 0-4).
+    #[clap(long, short)]
+    pub priority: Option<IssuePriority>,
+
+    /// Start date (YYYY-MM-DD).
+    #[clap(long)]
+    pub start_date: Option<String>,
+
+    /// Target date (YYYY-MM-DD).
+    #[clap(long)]
+    pub target_date: Option<String>,
+}
+
+impl CliCommand for CmdProjectCreate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let client = self.common.client()?;
+
+        let mut team_ids = Vec::new();
+        for key in &self.teams {
+            team_ids.push(resolve::team(&client, key).await?.id.into_inner());
+        }
+
+        let description = match (&self.description, &self.description_file) {
+            (Some(d), _) => Some(d.clone()),
+            (None, Some(path)) => Some(util::read_file_or_stdin(path)?),
+            (None, None) => None,
+        };
+
+        let lead_id = match &self.lead {
+            Some(name) => Some(resolve::user(&client, name).await?.id.into_inner()),
+            None => None,
+        };
+
+        let mut member_ids = Vec::new();
+        for name in &self.members {
+            member_ids.push(resolve::user(&client, name).await?.id.into_inner());
+        }
+
+        let status_id = match &self.status {
+            Some(name) => Some(
+                resolve::project_status(&client, name)
+                    .await?
+                    .id
+                    .into_inner(),
+            ),
+            None => None,
+        };
+
+        let input = ProjectCreateInput {
+            name: self.name.clone(),
+            team_ids,
+            description,
+            lead_id,
+            member_ids: Some(member_ids).filter(|ids| !ids.is_empty()),
+            status_id,
+            priority: self.priority.map(IssuePriority::value),
+            start_date: self
+                .start_date
+                .as_deref()
+                .map(util::parse_date)
+                .transpose()?,
+            target_date: self
+                .target_date
+                .as_deref()
+                .map(util::parse_date)
+                .transpose()?,
+        };
+
+        let payload = client
+            .query(ProjectCreate::build(ProjectCreateVariables { input }))
+            .await
+            .context("could not create project")?
+            .project_create;
+
+        let project = match payload.project {
+            Some(project) if payload.success => project,
+            _ => bail!("project creation failed"),
+        };
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Created project {}", project.name);
+                println!("{}", project.url);
+            }
+            format => println!("{}", render::render_value(&project, format)?),
+        }
+
+        Ok(())
+    }
+}
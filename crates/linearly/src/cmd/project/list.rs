@@ -1,5 +1,4 @@
-use anyhow::Context;
-use cynic::QueryBuilder;
+use futures::TryStreamExt;
 
 use linear_api::schema::{
     projects_list::{Project, ProjectList, ProjectListVariables},
@@ -7,17 +6,24 @@ use linear_api::schema::{
 };
 
 use crate::{
-    cmd::{CliCommand, CommonArgs, Format},
-    render::Render,
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{self, Render},
 };
 
+use super::ProjectTableColumns;
+
 #[derive(clap::Parser)]
 pub struct CmdProjectList {
     #[clap(flatten)]
     common: CommonArgs,
 
+    /// Maximum number of projects to return.
     #[clap(long, default_value = "50")]
-    pub limit: i32,
+    pub limit: usize,
+
+    /// Return all projects, ignoring --limit.
+    #[clap(long)]
+    pub all: bool,
 
     /// Include archived projects.
     #[clap(long, default_value = "false")]
@@ -25,34 +31,39 @@ pub struct CmdProjectList {
 
     #[clap(long, short, default_value = "table")]
     pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<ProjectTableColumns>,
 }
 
 impl CliCommand for CmdProjectList {
     type Output = ();
 
     async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<Project>("project")?;
         let vars = ProjectListVariables {
-            first: self.limit,
+            first: 0,
+            after: None,
             include_archived: self.archived,
             oder: PaginationOrderBy::CreatedAt,
         };
-        let op = ProjectList::build(vars);
-
-        let res = self
-            .common
-            .client()?
-            .run(op)
-            .await?
-            .data
-            .context("no data")?;
-
-        let output = match self.format {
-            Format::Table => {
-                let columns = Project::default_list_fields();
-                Project::render_list_table(&res.projects.nodes, &columns)
-            }
-            Format::Json => serde_json::to_string_pretty(&res.projects.nodes)?,
-        };
+        let limit = (!self.all).then_some(self.limit);
+
+        let client = self.common.client()?;
+        let projects = client.paginate::<ProjectList>(vars, limit);
+        if let Some(template) = &template {
+            return template.print_stream(projects).await;
+        }
+        if let Format::Ndjson = self.format {
+            return render::stream_ndjson(projects).await;
+        }
+        let projects: Vec<Project> = projects.try_collect().await?;
+
+        let output = Project::render_list(&projects, &columns, self.format)?;
 
         println!("{}", output);
 
@@ -1,15 +1,30 @@
 use comfy_table::{Attribute, Cell};
 
-use crate::render::Render;
+use linear_api::schema::projects::{ProjectDetail, ProjectUpdateHealthType};
 
-use super::CliCommand;
+use crate::{
+    render::{format_date, markdown, Render},
+    resolve,
+};
 
+use super::{issue::IssuePriority, CliCommand};
+
+pub mod archive;
+pub mod create;
 pub mod list;
+pub mod update;
+pub mod view;
 
 #[derive(clap::Subcommand)]
 pub enum CmdProject {
     #[clap(alias = "ls")]
     List(list::CmdProjectList),
+    View(view::CmdProjectView),
+    #[clap(alias = "new")]
+    Create(create::CmdProjectCreate),
+    Update(update::CmdProjectUpdate),
+    Archive(archive::CmdProjectArchive),
+    Unarchive(archive::CmdProjectUnarchive),
 }
 
 impl CliCommand for CmdProject {
@@ -18,17 +33,28 @@ impl CliCommand for CmdProject {
     async fn run(self) -> Result<(), anyhow::Error> {
         match self {
             CmdProject::List(c) => c.run().await,
+            CmdProject::View(c) => c.run().await,
+            CmdProject::Create(c) => c.run().await,
+            CmdProject::Update(c) => c.run().await,
+            CmdProject::Archive(c) => c.run().await,
+            CmdProject::Unarchive(c) => c.run().await,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
 pub enum ProjectTableColumns {
     Name,
+    Id,
+    Slug,
     Description,
     Status,
+    Creator,
+    Priority,
+    Url,
     Created,
     Updated,
+    Archived,
 }
 
 impl Render for linear_api::schema::projects_list::Project {
@@ -44,6 +70,19 @@ impl Render for linear_api::schema::projects_list::Project {
         ]
     }
 
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            ProjectTableColumns::Name,
+            ProjectTableColumns::Slug,
+            ProjectTableColumns::Description,
+            ProjectTableColumns::Status,
+            ProjectTableColumns::Priority,
+            ProjectTableColumns::Creator,
+            ProjectTableColumns::Created,
+            ProjectTableColumns::Updated,
+        ]
+    }
+
     fn default_detail_fields() -> Vec<Self::Fields> {
         Self::default_list_fields()
     }
@@ -51,38 +90,248 @@ impl Render for linear_api::schema::projects_list::Project {
     fn render_field_header(field: &Self::Fields) -> Cell {
         match field {
             ProjectTableColumns::Name => Cell::new("Name").add_attribute(Attribute::Bold),
+            ProjectTableColumns::Id => Cell::new("ID"),
+            ProjectTableColumns::Slug => Cell::new("Slug"),
             ProjectTableColumns::Description => Cell::new("Description"),
             ProjectTableColumns::Status => Cell::new("Status"),
+            ProjectTableColumns::Creator => Cell::new("Creator"),
+            ProjectTableColumns::Priority => Cell::new("Priority"),
+            ProjectTableColumns::Url => Cell::new("URL"),
             ProjectTableColumns::Created => Cell::new("Created"),
             ProjectTableColumns::Updated => Cell::new("Updated"),
+            ProjectTableColumns::Archived => Cell::new("Archived"),
         }
     }
 
     fn render_field(item: &Self, field: Self::Fields) -> Cell {
-        let timeformat = time::format_description::parse("[year]-[month]-[day]").unwrap();
-
         match field {
             ProjectTableColumns::Name => Cell::new(&item.name),
+            ProjectTableColumns::Id => Cell::new(item.id.inner()),
+            ProjectTableColumns::Slug => Cell::new(&item.slug_id),
             ProjectTableColumns::Description => Cell::new(&item.description),
             ProjectTableColumns::Status => Cell::new(&item.status.name),
-            ProjectTableColumns::Created => {
-                let v = item
-                    .created_at
-                    .parse()
-                    .unwrap()
-                    .format(&timeformat)
-                    .unwrap();
-                Cell::new(v)
+            ProjectTableColumns::Creator => Cell::new(
+                item.creator
+                    .as_ref()
+                    .map(|u| u.display_name.as_str())
+                    .unwrap_or_default(),
+            ),
+            ProjectTableColumns::Priority => {
+                Cell::new(IssuePriority::from_value(item.priority as f64))
             }
+            ProjectTableColumns::Url => Cell::new(&item.url),
+            ProjectTableColumns::Created => Cell::new(format_date(&item.created_at)),
+            ProjectTableColumns::Updated => Cell::new(format_date(&item.updated_at)),
+            ProjectTableColumns::Archived => Cell::new(
+                item.archived_at
+                    .as_ref()
+                    .map(format_date)
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ProjectDetailFields {
+    Name,
+    Status,
+    Health,
+    Progress,
+    Priority,
+    Lead,
+    Members,
+    Teams,
+    StartDate,
+    TargetDate,
+    Milestones,
+    Issues,
+    LatestUpdate,
+    Url,
+    Description,
+}
+
+fn health_name(health: ProjectUpdateHealthType) -> &'static str {
+    match health {
+        ProjectUpdateHealthType::OnTrack => "On track",
+        ProjectUpdateHealthType::AtRisk => "At risk",
+        ProjectUpdateHealthType::OffTrack => "Off track",
+    }
+}
 
-            ProjectTableColumns::Updated => {
-                let v = item
-                    .updated_at
-                    .parse()
-                    .unwrap()
-                    .format(&timeformat)
-                    .unwrap();
-                Cell::new(v)
+impl Render for ProjectDetail {
+    type Fields = ProjectDetailFields;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            ProjectDetailFields::Name,
+            ProjectDetailFields::Status,
+            ProjectDetailFields::Lead,
+            ProjectDetailFields::Progress,
+            ProjectDetailFields::TargetDate,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        vec![
+            ProjectDetailFields::Name,
+            ProjectDetailFields::Status,
+            ProjectDetailFields::Health,
+            ProjectDetailFields::Progress,
+            ProjectDetailFields::Priority,
+            ProjectDetailFields::Lead,
+            ProjectDetailFields::Members,
+            ProjectDetailFields::Teams,
+            ProjectDetailFields::StartDate,
+            ProjectDetailFields::TargetDate,
+            ProjectDetailFields::Milestones,
+            ProjectDetailFields::Issues,
+            ProjectDetailFields::LatestUpdate,
+            ProjectDetailFields::Url,
+            ProjectDetailFields::Description,
+        ]
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            ProjectDetailFields::Name => Cell::new("Name").add_attribute(Attribute::Bold),
+            ProjectDetailFields::Status => Cell::new("Status"),
+            ProjectDetailFields::Health => Cell::new("Health"),
+            ProjectDetailFields::Progress => Cell::new("Progress"),
+            ProjectDetailFields::Priority => Cell::new("Priority"),
+            ProjectDetailFields::Lead => Cell::new("Lead"),
+            ProjectDetailFields::Members => Cell::new("Members"),
+            ProjectDetailFields::Teams => Cell::new("Teams"),
+            ProjectDetailFields::StartDate => Cell::new("Start date"),
+            ProjectDetailFields::TargetDate => Cell::new("Target date"),
+            ProjectDetailFields::Milestones => Cell::new("Milestones"),
+            ProjectDetailFields::Issues => Cell::new("Issues"),
+            ProjectDetailFields::LatestUpdate => Cell::new("Latest update"),
+            ProjectDetailFields::Url => Cell::new("URL"),
+            ProjectDetailFields::Description => Cell::new("Description"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        let timeformat = time::format_description::parse("[year]-[month]-[day]").unwrap();
+
+        match field {
+            ProjectDetailFields::Name => Cell::new(&item.name),
+            ProjectDetailFields::Status => Cell::new(&item.status.name),
+            ProjectDetailFields::Health => {
+                Cell::new(item.health.map(health_name).unwrap_or_default())
+            }
+            ProjectDetailFields::Progress => Cell::new(format!(
+                "{:.0}% (scope: {})",
+                item.progress * 100.0,
+                item.scope
+            )),
+            ProjectDetailFields::Priority => {
+                Cell::new(IssuePriority::from_value(item.priority as f64))
+            }
+            ProjectDetailFields::Lead => Cell::new(
+                item.lead
+                    .as_ref()
+                    .map(|u| u.display_name.as_str())
+                    .unwrap_or_default(),
+            ),
+            ProjectDetailFields::Members => Cell::new(
+                item.members
+                    .nodes
+                    .iter()
+                    .map(|u| u.display_name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            ProjectDetailFields::Teams => Cell::new(
+                item.teams
+                    .nodes
+                    .iter()
+                    .map(|t| t.key.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            ProjectDetailFields::StartDate => Cell::new(
+                item.start_date
+                    .as_ref()
+                    .map(|d| d.0.as_str())
+                    .unwrap_or_default(),
+            ),
+            ProjectDetailFields::TargetDate => Cell::new(
+                item.target_date
+                    .as_ref()
+                    .map(|d| d.0.as_str())
+                    .unwrap_or_default(),
+            ),
+            ProjectDetailFields::Milestones => Cell::new(
+                item.project_milestones
+                    .nodes
+                    .iter()
+                    .map(|m| match &m.target_date {
+                        Some(date) => format!("{} ({})", m.name, date.0),
+                        None => m.name.clone(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            ProjectDetailFields::Issues => {
+                let mut counts = Vec::<(&str, &str, f64, usize)>::new();
+                for issue in &item.issues.nodes {
+                    let state = &issue.state;
+                    match counts.iter_mut().find(|(name, ..)| *name == state.name) {
+                        Some((.., count)) => *count += 1,
+                        None => counts.push((&state.name, &state.type_, state.position, 1)),
+                    }
+                }
+                counts.sort_by(|a, b| {
+                    resolve::state_type_order(a.1)
+                        .cmp(&resolve::state_type_order(b.1))
+                        .then(a.2.total_cmp(&b.2))
+                });
+                let mut lines = counts
+                    .iter()
+                    .map(|(name, _, _, count)| format!("{}: {}", name, count))
+                    .collect::<Vec<_>>();
+                if item.issues.page_info.has_next_page {
+                    lines.push(format!("(first {} issues)", item.issues.nodes.len()));
+                }
+                Cell::new(lines.join("\n"))
+            }
+            ProjectDetailFields::LatestUpdate => {
+                let latest = item
+                    .project_updates
+                    .nodes
+                    .iter()
+                    .max_by(|a, b| a.created_at.0.cmp(&b.created_at.0));
+                match latest {
+                    Some(update) => {
+                        let date = update
+                            .created_at
+                            .parse()
+                            .unwrap()
+                            .format(&timeformat)
+                            .unwrap();
+                        Cell::new(format!(
+                            "{} by {} on {}\n\n{}",
+                            health_name(update.health),
+                            update.user.display_name,
+                            date,
+                            markdown::render(&update.body)
+                        ))
+                    }
+                    None => Cell::new(""),
+                }
+            }
+            ProjectDetailFields::Url => Cell::new(&item.url),
+            ProjectDetailFields::Description => {
+                let mut text = item.description.clone();
+                if let Some(content) = item.content.as_deref().filter(|c| !c.trim().is_empty()) {
+                    if !text.is_empty() {
+                        text.push_str("\n\n");
+                    }
+                    text.push_str(content);
+                }
+                Cell::new(markdown::render(&text))
             }
         }
     }
@@ -0,0 +1,228 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use cynic::{MutationBuilder, QueryBuilder};
+
+use linear_api::schema::{
+    projects::{
+        ProjectGet, ProjectIdVariables, ProjectUpdateInput, ProjectUpdateMutation,
+        ProjectUpdateVariables,
+    },
+    Nullable,
+};
+
+use crate::{
+    cmd::{issue::IssuePriority, CliCommand, CommonArgs, Format},
+    render, resolve, util,
+};
+
+/// Update a project.
+///
+/// Optional fields can be cleared by passing "none".
+#[derive(clap::Parser)]
+pub struct CmdProjectUpdate {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    /// Project name, slug ID or ID.
+    pub project: String,
+
+    /// New project name.
+    #[clap(long)]
+    pub name: Option<String>,
+
+    /// New short project description.
+    #[clap(long, short, conflicts_with = "description_file")]
+    pub description: Option<String>,
+
+    /// Read the new description from a file.
+    /// Use "-" to read from stdin.
+    #[clap(long)]
+    pub description_file: Option<PathBuf>,
+
+    /// Name of the project lead, "me" or "none".
+    #[clap(long)]
+    pub lead: Option<String>,
+
+    /// Add a member. Can be specified multiple times.
+    #[clap(long = "add-member")]
+    pub add_members: Vec<String>,
+
+    /// Remove a member. Can be specified multiple times.
+    #[clap(long = "remove-member")]
+    pub remove_members: Vec<String>,
+
+    /// Add a team by KEY. Can be specified multiple times.
+    #[clap(long = "add-team")]
+    pub add_teams: Vec<String>,
+
+    /// Remove a team by KEY. Can be specified multiple times.
+    #[clap(long = "remove-team")]
+    pub remove_teams: Vec<String>,
+
+    /// Project status (eg: "Completed").
+    #[clap(long, short)]
+    pub status: Option<String>,
+
+    /// Priority: none, urgent, high, medium, low (or 0-4).
+    #[clap(long, short)]
+    pub priority: Option<IssuePriority>,
+
+    /// Start date (YYYY-MM-DD), or "none".
+    #[clap(long)]
+    pub start_date: Option<String>,
+
+    /// Target date (YYYY-MM-DD), or "none".
+    #[clap(long)]
+    pub target_date: Option<String>,
+}
+
+impl CliCommand for CmdProjectUpdate {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        if !self.has_changes() {
+            bail!("nothing to update: specify at least one field to change (see --help)");
+        }
+
+        let client = self.common.client()?;
+        let project_ref = resolve::project(&client, &self.project).await?;
+        let project = client
+            .query(ProjectGet::build(ProjectIdVariables {
+                id: project_ref.id.into_inner(),
+            }))
+            .await
+            .with_context(|| format!("could not load project {}", self.project))?
+            .project;
+
+        let mut input = ProjectUpdateInput {
+            name: self.name.clone(),
+            priority: self.priority.map(IssuePriority::value),
+            ..Default::default()
+        };
+
+        input.description = match (&self.description, &self.description_file) {
+            (Some(d), _) => Some(d.clone()),
+            (None, Some(path)) => Some(util::read_file_or_stdin(path)?),
+            (None, None) => None,
+        };
+
+        input.lead_id = match util::clearable(&self.lead) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(name)) => Some(Nullable::Value(
+                resolve::user(&client, name).await?.id.into_inner(),
+            )),
+        };
+
+        if !self.add_members.is_empty() || !self.remove_members.is_empty() {
+            let mut remove = Vec::new();
+            for name in &self.remove_members {
+                remove.push(resolve::user(&client, name).await?.id);
+            }
+            let mut member_ids = project
+                .members
+                .nodes
+                .iter()
+                .filter(|u| !remove.contains(&u.id))
+                .map(|u| u.id.clone().into_inner())
+                .collect::<Vec<_>>();
+            for name in &self.add_members {
+                let id = resolve::user(&client, name).await?.id.into_inner();
+                if !member_ids.contains(&id) {
+                    member_ids.push(id);
+                }
+            }
+            input.member_ids = Some(member_ids);
+        }
+
+        if !self.add_teams.is_empty() || !self.remove_teams.is_empty() {
+            let mut team_ids = project
+                .teams
+                .nodes
+                .iter()
+                .filter(|t| {
+                    !self
+                        .remove_teams
+                        .iter()
+                        .any(|key| key.eq_ignore_ascii_case(&t.key))
+                })
+                .map(|t| t.id.clone().into_inner())
+                .collect::<Vec<_>>();
+            for key in &self.add_teams {
+                let id = resolve::team(&client, key).await?.id.into_inner();
+                if !team_ids.contains(&id) {
+                    team_ids.push(id);
+                }
+            }
+            if team_ids.is_empty() {
+                bail!("a project must belong to at least one team");
+            }
+            input.team_ids = Some(team_ids);
+        }
+
+        if let Some(name) = &self.status {
+            input.status_id = Some(
+                resolve::project_status(&client, name)
+                    .await?
+                    .id
+                    .into_inner(),
+            );
+        }
+
+        input.start_date = match util::clearable(&self.start_date) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(value)) => Some(Nullable::Value(util::parse_date(value)?)),
+        };
+        input.target_date = match util::clearable(&self.target_date) {
+            None => None,
+            Some(None) => Some(Nullable::Null),
+            Some(Some(value)) => Some(Nullable::Value(util::parse_date(value)?)),
+        };
+
+        let payload = client
+            .query(ProjectUpdateMutation::build(ProjectUpdateVariables {
+                id: project.id.into_inner(),
+                input,
+            }))
+            .await
+            .with_context(|| format!("could not update project {}", project.name))?
+            .project_update;
+
+        let project = match payload.project {
+            Some(project) if payload.success => project,
+            _ => bail!("project update failed"),
+        };
+
+        match self.format.resolve() {
+            Format::Table => {
+                eprintln!("Updated project {}", project.name);
+                println!("{}", project.url);
+            }
+            format => println!("{}", render::render_value(&project, format)?),
+        }
+
+        Ok(())
+    }
+}
+
+impl CmdProjectUpdate {
+    fn has_changes(&self) -> bool {
+        self.name.is_some()
+            || self.description.is_some()
+            || self.description_file.is_some()
+            || self.lead.is_some()
+            || !self.add_members.is_empty()
+            || !self.remove_members.is_empty()
+            || !self.add_teams.is_empty()
+            || !self.remove_teams.is_empty()
+            || self.status.is_some()
+            || self.priority.is_some()
+            || self.start_date.is_some()
+            || self.target_date.is_some()
+    }
+}
@@ -0,0 +1,73 @@
+use std::process::Stdio;
+
+use anyhow::Context;
+use cynic::QueryBuilder;
+
+use linear_api::schema::projects::{ProjectDetail, ProjectGet, ProjectIdVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render::{markdown, Render},
+    resolve,
+};
+
+/// Show the details of a project.
+#[derive(clap::Parser)]
+pub struct CmdProjectView {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    /// Open the project in the browser.
+    #[clap(long, short)]
+    pub web: bool,
+
+    /// Project name, slug ID or ID.
+    pub project: String,
+}
+
+impl CliCommand for CmdProjectView {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+        let project_ref = resolve::project(&client, &self.project).await?;
+
+        let vars = ProjectIdVariables {
+            id: project_ref.id.into_inner(),
+        };
+        let project = client
+            .query(ProjectGet::build(vars))
+            .await
+            .with_context(|| format!("could not load project {}", self.project))?
+            .project;
+
+        let output = match &template {
+            Some(template) => template.render(&project)?,
+            None => {
+                if matches!(self.format.resolve(), Format::Table) {
+                    markdown::load_team_keys(&client).await?;
+                }
+                let fields = ProjectDetail::default_detail_fields();
+                ProjectDetail::render_detail(&project, &fields, self.format)?
+            }
+        };
+        println!("{}", output);
+
+        if self.web {
+            eprintln!("Opening URL {} in the browser...", project.url);
+            open::commands(&project.url)[0]
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()?;
+        }
+
+        Ok(())
+    }
+}
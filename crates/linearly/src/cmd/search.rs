@@ -0,0 +1,347 @@
+use std::fmt::Write;
+
+use colored::Colorize;
+use futures::TryStreamExt;
+
+use linear_api::{
+    pagination::PaginatedQuery,
+    schema::{
+        search::{
+            DocumentSearch, DocumentSearchResult, IssueSearch, IssueSearchResult, ProjectSearch,
+            ProjectSearchResult, SearchVariables,
+        },
+        JsonObject,
+    },
+    Client,
+};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render, resolve,
+};
+
+/// Characters of context shown on each side of the first match.
+const CONTEXT_CHARS: usize = 60;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchType {
+    Issue,
+    Project,
+    Document,
+}
+
+/// Search issues, projects and documents.
+///
+/// Results are grouped by type, with the matched context highlighted.
+/// A --template is rendered once per result, which has a `type` field of
+/// "issue", "project" or "document".
+#[derive(clap::Parser)]
+pub struct CmdSearch {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    /// Only search these types of results.
+    #[clap(long = "type", value_delimiter = ',')]
+    pub types: Vec<SearchType>,
+
+    /// Only search within this team.
+    /// Expects the short team KEY.
+    #[clap(long, short)]
+    pub team: Option<String>,
+
+    /// Also match the comments of issues, projects and documents.
+    #[clap(long)]
+    pub comments: bool,
+
+    /// Maximum number of results per type.
+    #[clap(long, default_value = "10")]
+    pub limit: usize,
+
+    /// Search terms.
+    #[clap(required = true)]
+    pub terms: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+struct SearchResults {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issues: Option<Vec<IssueSearchResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projects: Option<Vec<ProjectSearchResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    documents: Option<Vec<DocumentSearchResult>>,
+}
+
+/// A search result with its type, for templates.
+#[derive(serde::Serialize)]
+struct TypedResult<'a, T> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(flatten)]
+    result: &'a T,
+}
+
+impl<'a, T> TypedResult<'a, T> {
+    fn new(kind: &'static str, result: &'a T) -> Self {
+        Self { kind, result }
+    }
+}
+
+impl CliCommand for CmdSearch {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+        let team_id = match &self.team {
+            Some(key) => Some(resolve::team(&client, key).await?.id.into_inner()),
+            None => None,
+        };
+
+        let vars = SearchVariables {
+            term: self.terms.join(" "),
+            team_id,
+            include_comments: self.comments.then_some(true),
+            first: 0,
+            after: None,
+        };
+        let enabled = |ty| self.types.is_empty() || self.types.contains(&ty);
+
+        let (issues, projects, documents) = futures::try_join!(
+            search::<IssueSearch>(&client, &vars, self.limit, enabled(SearchType::Issue)),
+            search::<ProjectSearch>(&client, &vars, self.limit, enabled(SearchType::Project)),
+            search::<DocumentSearch>(&client, &vars, self.limit, enabled(SearchType::Document)),
+        )?;
+        let results = SearchResults {
+            issues,
+            projects,
+            documents,
+        };
+
+        if let Some(template) = &template {
+            for result in results.issues.iter().flatten() {
+                println!("{}", template.render(&TypedResult::new("issue", result))?);
+            }
+            for result in results.projects.iter().flatten() {
+                println!("{}", template.render(&TypedResult::new("project", result))?);
+            }
+            for result in results.documents.iter().flatten() {
+                println!(
+                    "{}",
+                    template.render(&TypedResult::new("document", result))?
+                );
+            }
+            return Ok(());
+        }
+        match self.format.resolve() {
+            Format::Table => print!("{}", render_results(&results, &vars.term)),
+            format => println!("{}", render::render_value(&results, format)?),
+        }
+
+        Ok(())
+    }
+}
+
+async fn search<Q>(
+    client: &Client,
+    vars: &SearchVariables,
+    limit: usize,
+    enabled: bool,
+) -> Result<Option<Vec<Q::Node>>, anyhow::Error>
+where
+    Q: PaginatedQuery<Variables = SearchVariables>,
+{
+    if !enabled {
+        return Ok(None);
+    }
+    let nodes = client
+        .paginate::<Q>(vars.clone(), Some(limit))
+        .try_collect()
+        .await?;
+    Ok(Some(nodes))
+}
+
+fn render_results(results: &SearchResults, term: &str) -> String {
+    let terms = term
+        .split_whitespace()
+        .map(|t| t.to_lowercase())
+        .collect::<Vec<_>>();
+    let mut out = String::new();
+
+    if let Some(issues) = &results.issues {
+        section(&mut out, "Issues", issues.len());
+        for issue in issues {
+            writeln!(
+                out,
+                "  {}  {} {}",
+                issue.identifier.bold(),
+                highlight(&issue.title, &terms),
+                format!("[{}]", issue.state.name).dimmed()
+            )
+            .unwrap();
+            let texts = issue.description.iter().map(String::as_str);
+            context(&mut out, texts, &issue.metadata, &terms);
+        }
+    }
+
+    if let Some(projects) = &results.projects {
+        section(&mut out, "Projects", projects.len());
+        for project in projects {
+            writeln!(
+                out,
+                "  {} {}",
+                highlight(&project.name, &terms).bold(),
+                format!("[{}]", project.status.name).dimmed()
+            )
+            .unwrap();
+            let texts =
+                std::iter::once(project.description.as_str()).chain(project.content.as_deref());
+            context(&mut out, texts, &project.metadata, &terms);
+        }
+    }
+
+    if let Some(documents) = &results.documents {
+        section(&mut out, "Documents", documents.len());
+        for document in documents {
+            let project = document
+                .project
+                .as_ref()
+                .map(|p| format!(" [{}]", p.name).dimmed().to_string())
+                .unwrap_or_default();
+            writeln!(
+                out,
+                "  {}{}",
+                highlight(&document.title, &terms).bold(),
+                project
+            )
+            .unwrap();
+            context(
+                &mut out,
+                document.content.as_deref(),
+                &document.metadata,
+                &terms,
+            );
+        }
+    }
+
+    out
+}
+
+fn section(out: &mut String, title: &str, count: usize) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    writeln!(
+        out,
+        "{} {}",
+        title.bold().underline(),
+        format!("({})", count).dimmed()
+    )
+    .unwrap();
+    if count == 0 {
+        writeln!(out, "  {}", "No matches".dimmed()).unwrap();
+    }
+}
+
+/// Write the context of the first match in `texts`, or in the strings of the
+/// search metadata (eg: matching comments).
+fn context<'a>(
+    out: &mut String,
+    texts: impl IntoIterator<Item = &'a str>,
+    metadata: &'a JsonObject,
+    terms: &[String],
+) {
+    let mut candidates = texts.into_iter().collect::<Vec<_>>();
+    collect_strings(&metadata.0, &mut candidates);
+
+    let snippet = candidates.into_iter().find_map(|text| {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        snippet(&text, terms)
+    });
+    if let Some(snippet) = snippet {
+        writeln!(out, "      {}", snippet).unwrap();
+    }
+}
+
+fn collect_strings<'a>(value: &'a serde_json::Value, out: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_strings(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// The text around the first match of a term, with all matches highlighted.
+fn snippet(text: &str, terms: &[String]) -> Option<String> {
+    let (start, end) = terms
+        .iter()
+        .filter_map(|term| find(text, term))
+        .min_by_key(|(start, _)| *start)?;
+
+    let from = text[..start]
+        .char_indices()
+        .rev()
+        .nth(CONTEXT_CHARS - 1)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let to = text[end..]
+        .char_indices()
+        .nth(CONTEXT_CHARS)
+        .map(|(i, _)| end + i)
+        .unwrap_or(text.len());
+
+    let mut snippet = highlight(&text[from..to], terms);
+    if from > 0 {
+        snippet.insert(0, '…');
+    }
+    if to < text.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+/// Highlight all matches of the terms.
+fn highlight(text: &str, terms: &[String]) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    loop {
+        let next = terms
+            .iter()
+            .filter_map(|term| find(rest, term))
+            .min_by_key(|(start, _)| *start);
+        let Some((start, end)) = next else {
+            out.push_str(rest);
+            return out;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&rest[start..end].yellow().bold().to_string());
+        rest = &rest[end..];
+    }
+}
+
+/// Find a lowercase term in a text, ignoring case.
+///
+/// Returns the byte range of the match.
+fn find(text: &str, term: &str) -> Option<(usize, usize)> {
+    if term.is_empty() {
+        return None;
+    }
+    text.char_indices().find_map(|(start, _)| {
+        let mut chars = text[start..].char_indices();
+        for expected in term.chars() {
+            let (_, c) = chars.next()?;
+            if !c.to_lowercase().eq(expected.to_lowercase()) {
+                return None;
+            }
+        }
+        let end = chars.next().map(|(i, _)| start + i).unwrap_or(text.len());
+        Some((start, end))
+    })
+}
@@ -1,5 +1,4 @@
-use anyhow::Context;
-use cynic::QueryBuilder;
+use futures::TryStreamExt;
 
 use linear_api::schema::{
     team_list::{Team, TeamList, TeamListVariables},
@@ -7,17 +6,24 @@ use linear_api::schema::{
 };
 
 use crate::{
-    cmd::{CliCommand, CommonArgs, Format},
-    render::Render,
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{self, Render},
 };
 
+use super::TeamTableColumns;
+
 #[derive(clap::Parser)]
 pub struct CmdTeamList {
     #[clap(flatten)]
     common: CommonArgs,
 
+    /// Maximum number of teams to return.
     #[clap(long, default_value = "50")]
-    pub limit: i32,
+    pub limit: usize,
+
+    /// Return all teams, ignoring --limit.
+    #[clap(long)]
+    pub all: bool,
 
     /// Include archived projects.
     #[clap(long, default_value = "false")]
@@ -25,33 +31,38 @@ pub struct CmdTeamList {
 
     #[clap(long, short, default_value = "table")]
     pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<TeamTableColumns>,
 }
 
 impl CliCommand for CmdTeamList {
     type Output = ();
 
     async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<Team>("team")?;
         let vars = TeamListVariables {
-            first: self.limit,
+            first: 0,
+            after: None,
             order: PaginationOrderBy::CreatedAt,
         };
-        let op = TeamList::build(vars);
-
-        let res = self
-            .common
-            .client()?
-            .run(op)
-            .await?
-            .data
-            .context("no data")?;
-
-        let output = match self.format {
-            Format::Table => {
-                let columns = Team::default_list_fields();
-                Team::render_list_table(&res.teams.nodes, &columns)
-            }
-            Format::Json => serde_json::to_string_pretty(&res.teams.nodes)?,
-        };
+        let limit = (!self.all).then_some(self.limit);
+
+        let client = self.common.client()?;
+        let teams = client.paginate::<TeamList>(vars, limit);
+        if let Some(template) = &template {
+            return template.print_stream(teams).await;
+        }
+        if let Format::Ndjson = self.format {
+            return render::stream_ndjson(teams).await;
+        }
+        let teams: Vec<Team> = teams.try_collect().await?;
+
+        let output = Team::render_list(&teams, &columns, self.format)?;
 
         println!("{}", output);
 
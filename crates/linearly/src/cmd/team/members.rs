@@ -0,0 +1,60 @@
+use futures::TryStreamExt;
+
+use linear_api::schema::teams::{TeamMember, TeamMembers, TeamMembersVariables};
+
+use crate::{
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::{self, Render},
+    resolve,
+};
+
+use super::MemberTableColumns;
+
+/// List the members of a team.
+#[derive(clap::Parser)]
+pub struct CmdTeamMembers {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<MemberTableColumns>,
+
+    /// Team KEY or name.
+    pub team: String,
+}
+
+impl CliCommand for CmdTeamMembers {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<TeamMember>("member")?;
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+
+        let vars = TeamMembersVariables {
+            id: team.id.into_inner(),
+            first: 0,
+            after: None,
+        };
+        let members = client.paginate::<TeamMembers>(vars, None);
+        if let Some(template) = &template {
+            return template.print_stream(members).await;
+        }
+        if let Format::Ndjson = self.format {
+            return render::stream_ndjson(members).await;
+        }
+        let members: Vec<TeamMember> = members.try_collect().await?;
+
+        let output = TeamMember::render_list(&members, &columns, self.format)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,29 @@
-use comfy_table::{Attribute, Cell};
+use comfy_table::{Attribute, Cell, Color};
 
-use crate::render::Render;
+use linear_api::schema::{
+    lookup::WorkflowStateRef,
+    teams::{TeamDetail, TeamMember},
+};
+
+use crate::{
+    render::{self, Render},
+    resolve,
+};
 
 use super::CliCommand;
 
 pub mod list;
+pub mod members;
+pub mod states;
+pub mod view;
 
 #[derive(clap::Subcommand)]
 pub enum CmdTeam {
     #[clap(alias = "ls")]
     List(list::CmdTeamList),
+    View(view::CmdTeamView),
+    Members(members::CmdTeamMembers),
+    States(states::CmdTeamStates),
 }
 
 impl CliCommand for CmdTeam {
@@ -18,16 +32,21 @@ impl CliCommand for CmdTeam {
     async fn run(self) -> Result<(), anyhow::Error> {
         match self {
             Self::List(c) => c.run().await,
+            Self::View(c) => c.run().await,
+            Self::Members(c) => c.run().await,
+            Self::States(c) => c.run().await,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
 pub enum TeamTableColumns {
     Key,
+    Id,
     Name,
     Description,
-    CreatedAt,
+    Created,
+    Updated,
 }
 
 impl Render for linear_api::schema::team_list::Team {
@@ -38,7 +57,18 @@ impl Render for linear_api::schema::team_list::Team {
             TeamTableColumns::Key,
             TeamTableColumns::Name,
             TeamTableColumns::Description,
-            TeamTableColumns::CreatedAt,
+            TeamTableColumns::Created,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            TeamTableColumns::Key,
+            TeamTableColumns::Id,
+            TeamTableColumns::Name,
+            TeamTableColumns::Description,
+            TeamTableColumns::Created,
+            TeamTableColumns::Updated,
         ]
     }
 
@@ -49,29 +79,346 @@ impl Render for linear_api::schema::team_list::Team {
     fn render_field_header(field: &Self::Fields) -> Cell {
         match field {
             TeamTableColumns::Key => Cell::new("Key").add_attribute(Attribute::Bold),
+            TeamTableColumns::Id => Cell::new("ID"),
             TeamTableColumns::Name => Cell::new("Name"),
             TeamTableColumns::Description => Cell::new("Description"),
-            TeamTableColumns::CreatedAt => Cell::new("Created"),
+            TeamTableColumns::Created => Cell::new("Created"),
+            TeamTableColumns::Updated => Cell::new("Updated"),
         }
     }
 
     fn render_field(item: &Self, field: Self::Fields) -> Cell {
-        let timeformat = time::format_description::parse("[year]-[month]-[day]").unwrap();
-
         match field {
             TeamTableColumns::Key => Cell::new(&item.key),
+            TeamTableColumns::Id => Cell::new(item.id.inner()),
             TeamTableColumns::Name => Cell::new(&item.name),
             TeamTableColumns::Description => {
                 Cell::new(item.description.as_deref().unwrap_or_default())
             }
-            TeamTableColumns::CreatedAt => {
-                let v = item
-                    .created_at
-                    .parse()
-                    .unwrap()
-                    .format(&timeformat)
-                    .unwrap();
-                Cell::new(v)
+            TeamTableColumns::Created => Cell::new(render::format_date(&item.created_at)),
+            TeamTableColumns::Updated => Cell::new(render::format_date(&item.updated_at)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum TeamDetailFields {
+    Key,
+    Name,
+    Description,
+    Private,
+    Timezone,
+    Members,
+    States,
+    Labels,
+    ActiveCycle,
+    Cycles,
+    Triage,
+    Estimation,
+}
+
+/// Capitalized workflow state type (eg: "Unstarted").
+fn state_type_name(kind: &str) -> String {
+    let mut chars = kind.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn weekday_name(day: f64) -> &'static str {
+    match day as u8 {
+        0 => "Sunday",
+        1 => "Monday",
+        2 => "Tuesday",
+        3 => "Wednesday",
+        4 => "Thursday",
+        5 => "Friday",
+        _ => "Saturday",
+    }
+}
+
+impl Render for TeamDetail {
+    type Fields = TeamDetailFields;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            TeamDetailFields::Key,
+            TeamDetailFields::Name,
+            TeamDetailFields::Description,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        vec![
+            TeamDetailFields::Key,
+            TeamDetailFields::Name,
+            TeamDetailFields::Description,
+            TeamDetailFields::Private,
+            TeamDetailFields::Timezone,
+            TeamDetailFields::Members,
+            TeamDetailFields::States,
+            TeamDetailFields::Labels,
+            TeamDetailFields::ActiveCycle,
+            TeamDetailFields::Cycles,
+            TeamDetailFields::Triage,
+            TeamDetailFields::Estimation,
+        ]
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            TeamDetailFields::Key => Cell::new("Key").add_attribute(Attribute::Bold),
+            TeamDetailFields::Name => Cell::new("Name"),
+            TeamDetailFields::Description => Cell::new("Description"),
+            TeamDetailFields::Private => Cell::new("Private"),
+            TeamDetailFields::Timezone => Cell::new("Timezone"),
+            TeamDetailFields::Members => Cell::new("Members"),
+            TeamDetailFields::States => Cell::new("Workflow states"),
+            TeamDetailFields::Labels => Cell::new("Labels"),
+            TeamDetailFields::ActiveCycle => Cell::new("Active cycle"),
+            TeamDetailFields::Cycles => Cell::new("Cycles"),
+            TeamDetailFields::Triage => Cell::new("Triage"),
+            TeamDetailFields::Estimation => Cell::new("Estimation"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        let timeformat = time::format_description::parse("[year]-[month]-[day]").unwrap();
+
+        match field {
+            TeamDetailFields::Key => Cell::new(&item.key),
+            TeamDetailFields::Name => Cell::new(&item.name),
+            TeamDetailFields::Description => {
+                Cell::new(item.description.as_deref().unwrap_or_default())
+            }
+            TeamDetailFields::Private => Cell::new(if item.private { "yes" } else { "no" }),
+            TeamDetailFields::Timezone => Cell::new(&item.timezone),
+            TeamDetailFields::Members => {
+                let mut names = item
+                    .members
+                    .nodes
+                    .iter()
+                    .map(|u| u.display_name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if item.members.page_info.has_next_page {
+                    names.push_str(", ...");
+                }
+                Cell::new(names)
+            }
+            TeamDetailFields::States => {
+                let mut states = item.states.nodes.iter().collect::<Vec<_>>();
+                states.sort_by(|a, b| {
+                    resolve::state_type_order(&a.type_)
+                        .cmp(&resolve::state_type_order(&b.type_))
+                        .then(a.position.total_cmp(&b.position))
+                });
+                let mut groups = Vec::<(&str, Vec<&str>)>::new();
+                for state in states {
+                    match groups.last_mut() {
+                        Some((kind, names)) if *kind == state.type_ => names.push(&state.name),
+                        _ => groups.push((&state.type_, vec![&state.name])),
+                    }
+                }
+                let lines = groups
+                    .iter()
+                    .map(|(kind, names)| format!("{}: {}", state_type_name(kind), names.join(", ")))
+                    .collect::<Vec<_>>();
+                Cell::new(lines.join("\n"))
+            }
+            TeamDetailFields::Labels => Cell::new(
+                item.labels
+                    .nodes
+                    .iter()
+                    .map(|l| l.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            TeamDetailFields::ActiveCycle => match &item.active_cycle {
+                Some(cycle) => {
+                    let starts = cycle
+                        .starts_at
+                        .parse()
+                        .unwrap()
+                        .format(&timeformat)
+                        .unwrap();
+                    let ends = cycle.ends_at.parse().unwrap().format(&timeformat).unwrap();
+                    let mut text = format!("#{}", cycle.number);
+                    if let Some(name) = &cycle.name {
+                        text.push_str(&format!(" {}", name));
+                    }
+                    Cell::new(format!("{} ({} - {})", text, starts, ends))
+                }
+                None => Cell::new(""),
+            },
+            TeamDetailFields::Cycles => {
+                if item.cycles_enabled {
+                    Cell::new(format!(
+                        "{} week(s), starting {}, {} week(s) cooldown, {} upcoming",
+                        item.cycle_duration,
+                        weekday_name(item.cycle_start_day),
+                        item.cycle_cooldown_time,
+                        item.upcoming_cycle_count,
+                    ))
+                } else {
+                    Cell::new("disabled")
+                }
+            }
+            TeamDetailFields::Triage => {
+                if item.triage_enabled {
+                    let mut text = "enabled".to_string();
+                    if let Some(state) = &item.triage_issue_state {
+                        text.push_str(&format!(" (state: {})", state.name));
+                    }
+                    if item.require_priority_to_leave_triage {
+                        text.push_str(", priority required to leave triage");
+                    }
+                    Cell::new(text)
+                } else {
+                    Cell::new("disabled")
+                }
+            }
+            TeamDetailFields::Estimation => {
+                let mut text = item.issue_estimation_type.clone();
+                if item.issue_estimation_type != "notUsed" {
+                    if item.issue_estimation_extended {
+                        text.push_str(", extended");
+                    }
+                    if item.issue_estimation_allow_zero {
+                        text.push_str(", zero allowed");
+                    }
+                    text.push_str(&format!(", default: {}", item.default_issue_estimate));
+                }
+                Cell::new(text)
+            }
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum MemberTableColumns {
+    User,
+    Id,
+    Name,
+    Email,
+    Admin,
+    Active,
+}
+
+impl Render for TeamMember {
+    type Fields = MemberTableColumns;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            MemberTableColumns::User,
+            MemberTableColumns::Name,
+            MemberTableColumns::Email,
+            MemberTableColumns::Admin,
+            MemberTableColumns::Active,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            MemberTableColumns::User,
+            MemberTableColumns::Id,
+            MemberTableColumns::Name,
+            MemberTableColumns::Email,
+            MemberTableColumns::Admin,
+            MemberTableColumns::Active,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        Self::default_list_fields()
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            MemberTableColumns::User => Cell::new("User").add_attribute(Attribute::Bold),
+            MemberTableColumns::Id => Cell::new("ID"),
+            MemberTableColumns::Name => Cell::new("Name"),
+            MemberTableColumns::Email => Cell::new("Email"),
+            MemberTableColumns::Admin => Cell::new("Admin"),
+            MemberTableColumns::Active => Cell::new("Active"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        match field {
+            MemberTableColumns::User => {
+                if item.is_me {
+                    Cell::new(format!("{} (you)", item.display_name))
+                } else {
+                    Cell::new(&item.display_name)
+                }
+            }
+            MemberTableColumns::Id => Cell::new(item.id.inner()),
+            MemberTableColumns::Name => Cell::new(&item.name),
+            MemberTableColumns::Email => Cell::new(&item.email),
+            MemberTableColumns::Admin => Cell::new(if item.admin { "yes" } else { "" }),
+            MemberTableColumns::Active => Cell::new(if item.active { "yes" } else { "no" }),
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum StateTableColumns {
+    Name,
+    Id,
+    Type,
+    Color,
+    Position,
+}
+
+impl Render for WorkflowStateRef {
+    type Fields = StateTableColumns;
+
+    fn default_list_fields() -> Vec<Self::Fields> {
+        vec![
+            StateTableColumns::Name,
+            StateTableColumns::Type,
+            StateTableColumns::Color,
+        ]
+    }
+
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        vec![
+            StateTableColumns::Name,
+            StateTableColumns::Id,
+            StateTableColumns::Type,
+            StateTableColumns::Color,
+            StateTableColumns::Position,
+        ]
+    }
+
+    fn default_detail_fields() -> Vec<Self::Fields> {
+        Self::default_list_fields()
+    }
+
+    fn render_field_header(field: &Self::Fields) -> Cell {
+        match field {
+            StateTableColumns::Name => Cell::new("Name").add_attribute(Attribute::Bold),
+            StateTableColumns::Type => Cell::new("Type"),
+            StateTableColumns::Id => Cell::new("ID"),
+            StateTableColumns::Color => Cell::new("Color"),
+            StateTableColumns::Position => Cell::new("Position"),
+        }
+    }
+
+    fn render_field(item: &Self, field: Self::Fields) -> Cell {
+        match field {
+            StateTableColumns::Name => Cell::new(&item.name),
+            StateTableColumns::Id => Cell::new(item.id.inner()),
+            StateTableColumns::Type => Cell::new(state_type_name(&item.type_)),
+            StateTableColumns::Position => Cell::new(item.position),
+            StateTableColumns::Color => {
+                let cell = Cell::new(&item.color);
+                match render::parse_color(&item.color) {
+                    Some((r, g, b)) => cell.fg(Color::Rgb { r, g, b }),
+                    None => cell,
+                }
             }
         }
     }
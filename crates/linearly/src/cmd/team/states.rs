@@ -0,0 +1,50 @@
+use linear_api::schema::lookup::WorkflowStateRef;
+
+use crate::{
+    cmd::{CliCommand, ColumnArgs, CommonArgs, Format, TemplateArgs},
+    render::Render,
+    resolve,
+};
+
+use super::StateTableColumns;
+
+/// List the workflow states of a team, grouped by type.
+#[derive(clap::Parser)]
+pub struct CmdTeamStates {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    #[clap(flatten)]
+    pub columns: ColumnArgs<StateTableColumns>,
+
+    /// Team KEY or name.
+    pub team: String,
+}
+
+impl CliCommand for CmdTeamStates {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let columns = self.columns.resolve::<WorkflowStateRef>("state")?;
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+
+        let mut states = resolve::workflow_states(&client, &team).await?;
+        states.sort_by_key(|s| resolve::state_type_order(&s.type_));
+
+        let output = match &template {
+            Some(template) => template.render_list(&states)?,
+            None => WorkflowStateRef::render_list(&states, &columns, self.format)?,
+        };
+        println!("{}", output);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Context;
+use cynic::QueryBuilder;
+
+use linear_api::schema::teams::{TeamDetail, TeamGet, TeamIdVariables};
+
+use crate::{
+    cmd::{CliCommand, CommonArgs, Format, TemplateArgs},
+    render::Render,
+    resolve,
+};
+
+/// Show the details of a team.
+///
+/// Includes members, workflow states, labels and cycle, triage and
+/// estimation settings.
+#[derive(clap::Parser)]
+pub struct CmdTeamView {
+    #[clap(flatten)]
+    common: CommonArgs,
+
+    #[clap(long, short, default_value = "table")]
+    pub format: Format,
+
+    #[clap(flatten)]
+    pub template: TemplateArgs,
+
+    /// Team KEY or name.
+    pub team: String,
+}
+
+impl CliCommand for CmdTeamView {
+    type Output = ();
+
+    async fn run(self) -> Result<(), anyhow::Error> {
+        let template = self.template.compile()?;
+        let client = self.common.client()?;
+        let team = resolve::team(&client, &self.team).await?;
+
+        let team = client
+            .query(TeamGet::build(TeamIdVariables {
+                id: team.id.into_inner(),
+            }))
+            .await
+            .with_context(|| format!("could not load team {}", self.team))?
+            .team;
+
+        let output = match &template {
+            Some(template) => template.render(&team)?,
+            None => {
+                let fields = TeamDetail::default_detail_fields();
+                TeamDetail::render_detail(&team, &fields, self.format)?
+            }
+        };
+        println!("{}", output);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use anyhow::Context;
 
@@ -7,12 +7,18 @@ pub const LINEAR_API_KEY_SETTINGS_URL: &str = "https://linear.app/wasmer/setting
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
 pub struct UserConfig {
     pub token: Option<String>,
+    /// Default table columns of list commands, by resource (eg: "issue").
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub columns: BTreeMap<String, Vec<String>>,
+    /// Named output templates for `--template`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, String>,
 }
 
 impl UserConfig {
     const FILENAME: &'static str = "config.toml";
 
-    fn path() -> PathBuf {
+    pub fn path() -> PathBuf {
         #[allow(deprecated)]
         std::env::home_dir()
             .expect("could not determine user home directory")
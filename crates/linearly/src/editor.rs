@@ -0,0 +1,146 @@
+//! Edit text in the user's editor, similar to `git commit`.
+
+use std::io::Write;
+
+use anyhow::{bail, Context};
+
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+/// Open `$VISUAL` or `$EDITOR` (falling back to `vi`) on a temporary file
+/// with the given content, and return the edited content.
+///
+/// `name` is used as the file name suffix, so editors can pick up the file
+/// type (eg: "issue.md").
+pub fn edit(initial: &str, name: &str) -> Result<String, anyhow::Error> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+
+    // Created with a random name, so it can not be redirected or clobbered
+    // through a file planted in the shared temp directory.
+    let mut file = tempfile::Builder::new()
+        .prefix("linear-")
+        .suffix(&format!("-{}", name))
+        .tempfile()
+        .context("could not create temporary file")?;
+    file.write_all(initial.as_bytes())
+        .and_then(|_| file.flush())
+        .with_context(|| format!("could not write temporary file '{}'", file.path().display()))?;
+
+    // The editor command may contain quoted arguments, eg: "code --wait".
+    let mut parts = shell_words::split(&editor)
+        .with_context(|| format!("invalid editor command '{}'", editor))?
+        .into_iter();
+    let program = parts.next().context("empty editor command")?;
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(file.path())
+        .status()
+        .with_context(|| format!("could not start editor '{}'", editor))?;
+    if !status.success() {
+        bail!("editor '{}' exited with {}", editor, status);
+    }
+
+    // Read by path: editors may replace the file instead of writing to it.
+    std::fs::read_to_string(file.path())
+        .with_context(|| format!("could not read temporary file '{}'", file.path().display()))
+}
+
+/// Issue fields edited as a markdown file with a front matter header.
+///
+/// ```text
+/// ---
+/// title: Fix the login page
+/// team: ENG
+/// assignee: me
+/// labels: bug, frontend
+/// priority: high
+/// ---
+///
+/// Description in markdown...
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IssueDraft {
+    pub title: String,
+    pub team: String,
+    pub assignee: String,
+    pub labels: Vec<String>,
+    pub priority: String,
+    pub description: String,
+}
+
+impl IssueDraft {
+    /// Render the draft as editable markdown.
+    ///
+    /// `hints` are added as comments to the front matter.
+    pub fn to_markdown(&self, hints: &[String]) -> String {
+        let mut out = String::new();
+        out.push_str(FRONT_MATTER_DELIMITER);
+        out.push('\n');
+        out.push_str(&format!("title: {}\n", self.title));
+        out.push_str(&format!("team: {}\n", self.team));
+        out.push_str(&format!("assignee: {}\n", self.assignee));
+        out.push_str(&format!("labels: {}\n", self.labels.join(", ")));
+        out.push_str(&format!("priority: {}\n", self.priority));
+        for hint in hints {
+            out.push_str(&format!("# {}\n", hint));
+        }
+        out.push_str(FRONT_MATTER_DELIMITER);
+        out.push_str("\n\n");
+        out.push_str(&self.description);
+        if !self.description.ends_with('\n') {
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Parse edited markdown back into a draft.
+    pub fn parse(content: &str) -> Result<Self, anyhow::Error> {
+        let mut lines = content.lines();
+        if lines.next().map(str::trim) != Some(FRONT_MATTER_DELIMITER) {
+            bail!("missing front matter: the file must start with '---'");
+        }
+
+        let mut draft = Self::default();
+        let mut closed = false;
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line == FRONT_MATTER_DELIMITER {
+                closed = true;
+                break;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .with_context(|| format!("invalid front matter line: '{}'", line))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "title" => draft.title = value,
+                "team" => draft.team = value,
+                "assignee" => draft.assignee = value,
+                "labels" => {
+                    draft.labels = value
+                        .split(',')
+                        .map(|l| l.trim().to_string())
+                        .filter(|l| !l.is_empty())
+                        .collect();
+                }
+                "priority" => draft.priority = value,
+                other => bail!("unknown front matter field '{}'", other),
+            }
+        }
+        if !closed {
+            bail!("front matter is not terminated with '---'");
+        }
+
+        let body = lines.collect::<Vec<_>>().join("\n");
+        draft.description = body.trim().to_string();
+
+        Ok(draft)
+    }
+}
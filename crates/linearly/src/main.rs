@@ -1,17 +1,127 @@
 use clap::Parser;
-use cmd::CliCommand;
+use cmd::{CliCommand, NoTokenError};
 use colored::Colorize;
 
 mod cmd;
 mod config;
+mod editor;
 mod render;
+mod resolve;
 mod util;
 
 #[tokio::main]
 async fn main() {
     let args = cmd::Args::parse();
     if let Err(err) = args.run().await {
-        eprintln!("{}: {}", "ERROR".red(), err);
-        std::process::exit(1);
+        // Include the causes, eg: the API error behind "could not load issue".
+        eprintln!("{}: {:#}", "ERROR".red(), err);
+        if let Some(hint) = error_hint(&err) {
+            eprintln!("{}", hint);
+        }
+        std::process::exit(exit_code(&err));
+    }
+}
+
+/// Exit codes for the different kinds of failures.
+///
+/// 2 is skipped because clap uses it for usage errors.
+mod exit_code {
+    pub const GENERIC: i32 = 1;
+    pub const AUTHENTICATION: i32 = 3;
+    pub const NOT_FOUND: i32 = 4;
+    pub const RATE_LIMITED: i32 = 5;
+    pub const VALIDATION: i32 = 6;
+    pub const NETWORK: i32 = 7;
+}
+
+fn exit_code(err: &anyhow::Error) -> i32 {
+    if err.downcast_ref::<NoTokenError>().is_some() {
+        return exit_code::AUTHENTICATION;
+    }
+
+    match err.downcast_ref::<linear_api::Error>() {
+        Some(linear_api::Error::Authentication { .. }) => exit_code::AUTHENTICATION,
+        Some(linear_api::Error::NotFound { .. }) => exit_code::NOT_FOUND,
+        Some(linear_api::Error::RateLimited { .. }) => exit_code::RATE_LIMITED,
+        Some(linear_api::Error::Validation { .. }) => exit_code::VALIDATION,
+        Some(linear_api::Error::Network(_) | linear_api::Error::Http { .. }) => exit_code::NETWORK,
+        _ => exit_code::GENERIC,
+    }
+}
+
+fn error_hint(err: &anyhow::Error) -> Option<String> {
+    match err.downcast_ref::<linear_api::Error>()? {
+        linear_api::Error::Authentication { .. } => Some(
+            "Your API token is invalid or lacks permissions. Run 'linear login' to configure a new one."
+                .to_string(),
+        ),
+        linear_api::Error::RateLimited { rate_limit, .. } => {
+            let reset = rate_limit.reset_at()?;
+            let format = time::format_description::parse("[hour]:[minute]:[second] UTC").ok()?;
+            Some(format!(
+                "The Linear API rate limit resets at {}.",
+                reset.format(&format).ok()?
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use linear_api::rate_limit::RateLimitInfo;
+    use serde_json::json;
+
+    use super::*;
+
+    /// The exit code for the errors of a GraphQL response body, behind a
+    /// context like the commands add.
+    fn exit_code_for(errors: serde_json::Value) -> i32 {
+        let errors = serde_json::from_value(errors).unwrap();
+        let err = linear_api::Error::from_graphql(errors, &RateLimitInfo::default());
+        let err = Err::<(), _>(err)
+            .context("could not load issue ENG-1")
+            .unwrap_err();
+        exit_code(&err)
+    }
+
+    #[test]
+    fn exit_codes() {
+        let auth = json!([{
+            "message": "Authentication required, not authenticated",
+            "extensions": { "type": "authentication error", "code": "AUTHENTICATION_ERROR" }
+        }]);
+        assert_eq!(exit_code_for(auth), exit_code::AUTHENTICATION);
+
+        let not_found = json!([{
+            "message": "Entity not found: Issue",
+            "extensions": { "type": "invalid input", "code": "INPUT_ERROR" }
+        }]);
+        assert_eq!(exit_code_for(not_found), exit_code::NOT_FOUND);
+
+        let rate_limited = json!([{
+            "message": "Rate limit exceeded",
+            "extensions": { "code": "RATELIMITED" }
+        }]);
+        assert_eq!(exit_code_for(rate_limited), exit_code::RATE_LIMITED);
+
+        let validation = json!([{
+            "message": "Argument Validation Error",
+            "extensions": { "code": "INVALID_INPUT", "userError": true }
+        }]);
+        assert_eq!(exit_code_for(validation), exit_code::VALIDATION);
+
+        let other = json!([{ "message": "Internal server error" }]);
+        assert_eq!(exit_code_for(other), exit_code::GENERIC);
+
+        assert_eq!(
+            exit_code(&anyhow::anyhow!("team 'ENG' not found")),
+            exit_code::GENERIC
+        );
+        assert_eq!(
+            exit_code(&anyhow::Error::new(NoTokenError)),
+            exit_code::AUTHENTICATION
+        );
     }
 }
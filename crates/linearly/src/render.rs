@@ -1,4 +1,245 @@
+pub mod markdown;
+pub mod query;
+pub mod template;
+
+use colored::Colorize;
 use comfy_table::{Cell, ContentArrangement, Table};
+use futures::{Stream, TryStreamExt};
+use linear_api::schema::DateTime;
+use serde::Serialize;
+
+use crate::cmd::Format;
+
+/// Parse a "#rrggbb" color into RGB components.
+pub fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    Some(((value >> 16) as u8, (value >> 8) as u8, value as u8))
+}
+
+/// A small block in the given "#rrggbb" color.
+pub fn color_swatch(color: &str) -> String {
+    match parse_color(color) {
+        Some((r, g, b)) => "■".truecolor(r, g, b).to_string(),
+        None => "■".to_string(),
+    }
+}
+
+/// Format a timestamp as YYYY-MM-DD.
+pub fn format_date(value: &DateTime) -> String {
+    let format = time::format_description::parse("[year]-[month]-[day]").unwrap();
+    value
+        .parse()
+        .ok()
+        .and_then(|v| v.format(&format).ok())
+        .unwrap_or_else(|| value.0.clone())
+}
+
+/// Remove terminal escape sequences (colors, hyperlinks) from a string.
+pub fn strip_ansi(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: ends with a byte in the range @..~.
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: ends with BEL or ESC \.
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' {
+                        chars.next_if_eq(&'\\');
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// The plain text content of a table cell.
+fn cell_text(cell: &Cell) -> String {
+    strip_ansi(&cell.content())
+}
+
+/// Render a value in a serialization format, after applying `--query`.
+///
+/// Tabular formats show one row per array element (or a single row for
+/// other values), with a column for each top level field. Nested values are
+/// shown as JSON.
+pub fn render_value<T: Serialize>(value: &T, format: Format) -> Result<String, anyhow::Error> {
+    let mut value = serde_json::to_value(value)?;
+    if let Some(mut results) = query::apply(value.clone())? {
+        match format {
+            // Print each result like jq does.
+            Format::Json | Format::Table => return query::format_results(&results, false),
+            Format::Ndjson => return query::format_results(&results, true),
+            _ if results.len() == 1 => value = results.remove(0),
+            _ => value = serde_json::Value::Array(results),
+        }
+    }
+
+    match format {
+        Format::Json => Ok(serde_json::to_string_pretty(&value)?),
+        Format::Yaml => Ok(serde_yaml::to_string(&value)?.trim_end().to_string()),
+        Format::Ndjson => {
+            let lines = match value {
+                serde_json::Value::Array(items) => items
+                    .iter()
+                    .map(serde_json::to_string)
+                    .collect::<Result<Vec<_>, _>>()?,
+                value => vec![serde_json::to_string(&value)?],
+            };
+            Ok(lines.join("\n"))
+        }
+        Format::Table | Format::Csv | Format::Tsv | Format::Markdown => {
+            let items = match value {
+                serde_json::Value::Array(items) => items,
+                value => vec![value],
+            };
+
+            let mut header = Vec::<String>::new();
+            for item in &items {
+                if let serde_json::Value::Object(map) = item {
+                    for key in map.keys() {
+                        if !header.contains(key) {
+                            header.push(key.clone());
+                        }
+                    }
+                }
+            }
+
+            let text = |value: Option<&serde_json::Value>| match value {
+                None | Some(serde_json::Value::Null) => String::new(),
+                Some(serde_json::Value::String(v)) => v.clone(),
+                Some(v) => v.to_string(),
+            };
+            let rows = items
+                .iter()
+                .map(|item| match item {
+                    serde_json::Value::Object(map) => {
+                        header.iter().map(|key| text(map.get(key))).collect()
+                    }
+                    value => vec![text(Some(value))],
+                })
+                .collect();
+            if header.is_empty() {
+                header.push("value".to_string());
+            }
+            render_rows(header, rows, format)
+        }
+    }
+}
+
+/// Render rows of plain text in a tabular format.
+fn render_rows(
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    format: Format,
+) -> Result<String, anyhow::Error> {
+    match format {
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(&header)?;
+            for row in &rows {
+                writer.write_record(row)?;
+            }
+            let out = String::from_utf8(writer.into_inner()?)?;
+            Ok(out.trim_end().to_string())
+        }
+        // TSV has no quoting, so tabs and line breaks are escaped.
+        Format::Tsv => {
+            let line = |cells: &[String]| {
+                cells
+                    .iter()
+                    .map(|c| {
+                        c.replace('\\', "\\\\")
+                            .replace('\t', "\\t")
+                            .replace('\n', "\\n")
+                            .replace('\r', "\\r")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            };
+            let mut out = vec![line(&header)];
+            out.extend(rows.iter().map(|row| line(row)));
+            Ok(out.join("\n"))
+        }
+        Format::Markdown => {
+            let line = |cells: &[String]| {
+                let cells = cells
+                    .iter()
+                    .map(|c| {
+                        c.trim()
+                            .replace('|', "\\|")
+                            .replace("\r\n", "<br>")
+                            .replace(['\r', '\n'], "<br>")
+                    })
+                    .collect::<Vec<_>>();
+                format!("| {} |", cells.join(" | "))
+            };
+            let mut out = vec![line(&header), format!("|{}", " --- |".repeat(header.len()))];
+            out.extend(rows.iter().map(|row| line(row)));
+            Ok(out.join("\n"))
+        }
+        _ => {
+            let mut table = Table::new();
+            table
+                .set_content_arrangement(ContentArrangement::Dynamic)
+                .load_preset(comfy_table::presets::UTF8_HORIZONTAL_ONLY)
+                .set_header(header)
+                .add_rows(rows);
+            Ok(table.to_string())
+        }
+    }
+}
+
+/// Print the items of a stream as JSON lines as soon as they arrive.
+pub async fn stream_ndjson<T, E, S>(stream: S) -> Result<(), anyhow::Error>
+where
+    T: Serialize,
+    E: std::error::Error + Send + Sync + 'static,
+    S: Stream<Item = Result<T, E>>,
+{
+    // Queries apply to the whole output.
+    if query::is_active() {
+        let items: Vec<T> = stream.try_collect().await?;
+        println!("{}", render_value(&items, Format::Ndjson)?);
+        return Ok(());
+    }
+    stream_lines(stream, |item| Ok(serde_json::to_string(item)?)).await
+}
+
+/// Print a line for each item of a stream as soon as it arrives.
+pub async fn stream_lines<T, E, S, F>(stream: S, mut line: F) -> Result<(), anyhow::Error>
+where
+    E: std::error::Error + Send + Sync + 'static,
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(&T) -> Result<String, anyhow::Error>,
+{
+    let mut stream = std::pin::pin!(stream);
+    while let Some(item) = stream.try_next().await? {
+        println!("{}", line(&item)?);
+    }
+    Ok(())
+}
 
 pub trait Render: Sized {
     type Fields: Copy;
@@ -9,6 +250,11 @@ pub trait Render: Sized {
 
     fn default_list_fields() -> Vec<Self::Fields>;
 
+    /// Columns shown with `--wide`.
+    fn wide_list_fields() -> Vec<Self::Fields> {
+        Self::default_list_fields()
+    }
+
     fn default_detail_fields() -> Vec<Self::Fields>;
 
     fn render_list_table_header(fields: &[Self::Fields]) -> Vec<Cell> {
@@ -66,4 +312,153 @@ pub trait Render: Sized {
 
         table.to_string()
     }
+
+    /// Render items in the given output format.
+    ///
+    /// Tabular formats use `fields` as columns, unless `--query` is active:
+    /// the query results are shown instead.
+    fn render_list(
+        items: &[Self],
+        fields: &[Self::Fields],
+        format: Format,
+    ) -> Result<String, anyhow::Error>
+    where
+        Self: Serialize,
+    {
+        if query::is_active() {
+            return render_value(&items, format);
+        }
+        match format {
+            Format::Table => Ok(Self::render_list_table(items, fields)),
+            Format::Csv | Format::Tsv | Format::Markdown => {
+                let header = fields
+                    .iter()
+                    .map(|f| cell_text(&Self::render_field_header(f)))
+                    .collect();
+                let rows = items
+                    .iter()
+                    .map(|item| {
+                        fields
+                            .iter()
+                            .map(|f| cell_text(&Self::render_field(item, *f)))
+                            .collect()
+                    })
+                    .collect();
+                render_rows(header, rows, format)
+            }
+            Format::Json | Format::Ndjson | Format::Yaml => render_value(&items, format),
+        }
+    }
+
+    /// Render a single item in the given output format.
+    ///
+    /// CSV and TSV show the item as a single row, Markdown as a table with
+    /// a row per field.
+    fn render_detail(
+        item: &Self,
+        fields: &[Self::Fields],
+        format: Format,
+    ) -> Result<String, anyhow::Error>
+    where
+        Self: Serialize,
+    {
+        if query::is_active() {
+            return render_value(item, format);
+        }
+        match format {
+            Format::Table => Ok(Self::render_detail_table(item, fields)),
+            Format::Csv | Format::Tsv => {
+                Self::render_list(std::slice::from_ref(item), fields, format)
+            }
+            Format::Markdown => {
+                let rows = fields
+                    .iter()
+                    .map(|f| {
+                        vec![
+                            cell_text(&Self::render_field_header(f)),
+                            cell_text(&Self::render_field(item, *f)),
+                        ]
+                    })
+                    .collect();
+                render_rows(vec!["Field".to_string(), "Value".to_string()], rows, format)
+            }
+            Format::Json | Format::Ndjson | Format::Yaml => render_value(item, format),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn rows(format: Format) -> String {
+        let header = vec!["Title".to_string(), "Labels".to_string()];
+        let rows = vec![
+            vec!["Fix a, b and \"c\"".to_string(), "bug".to_string()],
+            vec!["Tabs\tand\r\nlines\n".to_string(), "a|b".to_string()],
+            vec![r"C:\path\n".to_string(), String::new()],
+        ];
+        render_rows(header, rows, format).unwrap()
+    }
+
+    #[test]
+    fn csv_quotes_special_characters() {
+        assert_eq!(
+            rows(Format::Csv),
+            "Title,Labels\n\
+             \"Fix a, b and \"\"c\"\"\",bug\n\
+             \"Tabs\tand\r\nlines\n\",a|b\n\
+             C:\\path\\n,"
+        );
+    }
+
+    #[test]
+    fn tsv_escapes_tabs_and_line_breaks() {
+        assert_eq!(
+            rows(Format::Tsv),
+            "Title\tLabels\n\
+             Fix a, b and \"c\"\tbug\n\
+             Tabs\\tand\\r\\nlines\\n\ta|b\n\
+             C:\\\\path\\\\n\t"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_line_breaks() {
+        assert_eq!(
+            rows(Format::Markdown),
+            "| Title | Labels |\n\
+             | --- | --- |\n\
+             | Fix a, b and \"c\" | bug |\n\
+             | Tabs\tand<br>lines | a\\|b |\n\
+             | C:\\path\\n |  |"
+        );
+    }
+
+    #[test]
+    fn cells_are_plain_text() {
+        let cell = Cell::new("\x1b[1m\x1b[31mUrgent\x1b[0m");
+        assert_eq!(cell_text(&cell), "Urgent");
+    }
+
+    #[test]
+    fn values_as_rows() {
+        let value = json!([
+            { "id": "ENG-1", "labels": ["bug"], "estimate": 2 },
+            { "id": "ENG-2", "title": "a|b", "estimate": null },
+        ]);
+        assert_eq!(
+            render_value(&value, Format::Markdown).unwrap(),
+            "| id | labels | estimate | title |\n\
+             | --- | --- | --- | --- |\n\
+             | ENG-1 | [\"bug\"] | 2 |  |\n\
+             | ENG-2 |  |  | a\\|b |"
+        );
+        assert_eq!(
+            render_value(&json!("plain"), Format::Csv).unwrap(),
+            "value\nplain"
+        );
+    }
 }
@@ -0,0 +1,403 @@
+//! Render markdown (issue descriptions, comments, ...) for the terminal.
+
+use std::{io::IsTerminal, sync::OnceLock};
+
+use colored::{ColoredString, Colorize};
+use linear_api::Client;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use syntect::{easy::HighlightLines, highlighting::ThemeSet, parsing::SyntaxSet};
+
+use crate::resolve;
+
+const RULE_WIDTH: usize = 40;
+const CODE_THEME: &str = "base16-ocean.dark";
+
+/// Render markdown for stdout.
+///
+/// Returns styled text with ANSI escape codes if stdout is a terminal, and
+/// the unchanged markdown otherwise.
+pub fn render(markdown: &str) -> String {
+    if styled() {
+        to_ansi(markdown)
+    } else {
+        markdown.trim().to_string()
+    }
+}
+
+fn styled() -> bool {
+    std::io::stdout().is_terminal() && colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
+/// Keys of the teams in the workspace, see [`load_team_keys`].
+static TEAM_KEYS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Load the team keys, so issue identifiers (eg: `MYTEAM-123`) are
+/// highlighted in rendered markdown.
+///
+/// Identifiers are only highlighted for known teams, otherwise "UTF-8" or
+/// "SHA-256" would be too. Nothing is loaded if the output is not styled.
+pub async fn load_team_keys(client: &Client) -> Result<(), anyhow::Error> {
+    if styled() && TEAM_KEYS.get().is_none() {
+        let teams = resolve::teams(client).await?;
+        let _ = TEAM_KEYS.set(teams.into_iter().map(|team| team.key).collect());
+    }
+    Ok(())
+}
+
+fn is_team_key(key: &str) -> bool {
+    TEAM_KEYS
+        .get()
+        .is_some_and(|keys| keys.iter().any(|k| k == key))
+}
+
+/// Render markdown as text styled with ANSI escape codes.
+pub fn to_ansi(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+
+    let mut renderer = Renderer {
+        at_line_start: true,
+        ..Default::default()
+    };
+    for event in Parser::new_ext(markdown, options) {
+        renderer.event(event);
+    }
+    renderer.out.trim_end().to_string()
+}
+
+/// Matches Linear mentions in plain text: `@username` and issue identifiers
+/// like `TEAM-123`, with the team key captured as `team`.
+fn mention_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?:^|\B)@[\w.-]*\w|\b(?P<team>[A-Z][A-Z0-9]{0,9})-[0-9]+\b").unwrap()
+    })
+}
+
+fn syntaxes() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn themes() -> &'static ThemeSet {
+    static SET: OnceLock<ThemeSet> = OnceLock::new();
+    SET.get_or_init(ThemeSet::load_defaults)
+}
+
+/// Highlight code with syntect, if the language is known.
+fn highlight(code: &str, lang: &str) -> Option<Vec<String>> {
+    let syntax = syntaxes().find_syntax_by_token(lang)?;
+    let theme = themes().themes.get(CODE_THEME)?;
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    syntect::util::LinesWithEndings::from(code)
+        .map(|line| {
+            let ranges = highlighter.highlight_line(line, syntaxes()).ok()?;
+            let escaped = syntect::util::as_24_bit_terminal_escaped(&ranges, false);
+            Some(format!("{}\x1b[0m", escaped.trim_end_matches('\n')))
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Renderer {
+    out: String,
+    at_line_start: bool,
+    /// Emit an empty line before the next block.
+    pending_blank: bool,
+
+    strong: usize,
+    emphasis: usize,
+    strikethrough: usize,
+    heading: Option<HeadingLevel>,
+    /// Target URLs of the links currently being rendered.
+    links: Vec<(String, usize)>,
+
+    quote_depth: usize,
+    /// Continuation indent of the open list items.
+    item_indents: Vec<usize>,
+    /// Next number of each open list, `None` for bullet lists.
+    lists: Vec<Option<u64>>,
+
+    code_block: Option<(String, String)>,
+    table_cell: usize,
+}
+
+impl Renderer {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some((_, code)) = &mut self.code_block {
+                    code.push_str(&text);
+                } else {
+                    self.text(&text);
+                }
+            }
+            Event::Code(code) => {
+                let styled = format!("`{}`", code).yellow();
+                self.write(&styled.to_string());
+            }
+            Event::SoftBreak | Event::HardBreak => self.newline(),
+            Event::Rule => {
+                self.block_start();
+                self.write(&"─".repeat(RULE_WIDTH).dimmed().to_string());
+                self.block_end();
+            }
+            Event::TaskListMarker(checked) => {
+                // Replace the bullet with a checkbox.
+                if self.out.ends_with("• ") {
+                    self.out.truncate(self.out.len() - "• ".len());
+                }
+                let marker = if checked { "☑ " } else { "☐ " };
+                self.write(marker);
+            }
+            Event::FootnoteReference(name) => self.write(&format!("[^{}]", name)),
+            Event::Html(html) | Event::InlineHtml(html) => {
+                self.write(&html.dimmed().to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.block_start(),
+            Tag::Heading { level, .. } => {
+                self.block_start();
+                self.heading = Some(level);
+            }
+            Tag::BlockQuote(_) => {
+                self.block_start();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.block_start();
+                let lang = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code_block = Some((lang, String::new()));
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.block_start();
+                } else {
+                    self.ensure_line_start();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.ensure_line_start();
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        let bullet = format!("{}. ", number);
+                        *number += 1;
+                        bullet
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.write(&bullet);
+                self.item_indents.push(bullet.chars().count());
+            }
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link { dest_url, .. } => self.links.push((dest_url.to_string(), self.out.len())),
+            Tag::Image { dest_url, .. } => {
+                self.write(&"[image: ".dimmed().to_string());
+                self.links.push((dest_url.to_string(), self.out.len()));
+            }
+            Tag::Table(_) => self.block_start(),
+            Tag::TableHead | Tag::TableRow => {
+                self.ensure_line_start();
+                self.table_cell = 0;
+            }
+            Tag::TableCell => {
+                if self.table_cell > 0 {
+                    self.write(&" │ ".dimmed().to_string());
+                }
+                self.table_cell += 1;
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.block_start();
+                self.write(&format!("[^{}]: ", name));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Table | TagEnd::FootnoteDefinition => self.block_end(),
+            TagEnd::Heading(_) => {
+                self.heading = None;
+                self.block_end();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                self.block_end();
+            }
+            TagEnd::CodeBlock => {
+                if let Some((lang, code)) = self.code_block.take() {
+                    self.code(&lang, &code);
+                }
+                self.block_end();
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.block_end();
+                }
+            }
+            TagEnd::Item => {
+                self.item_indents.pop();
+                self.ensure_line_start();
+                // Loose list items contain paragraphs: keep items compact.
+                self.pending_blank = false;
+            }
+            TagEnd::Emphasis => self.emphasis = self.emphasis.saturating_sub(1),
+            TagEnd::Strong => self.strong = self.strong.saturating_sub(1),
+            TagEnd::Strikethrough => self.strikethrough = self.strikethrough.saturating_sub(1),
+            TagEnd::Link => {
+                if let Some((url, start)) = self.links.pop() {
+                    let text = &self.out[start..];
+                    if !url.is_empty() && !text.contains(url.as_str()) {
+                        self.write(&format!(" ({})", url).dimmed().to_string());
+                    }
+                }
+            }
+            TagEnd::Image => {
+                if let Some((url, _)) = self.links.pop() {
+                    self.write(&format!("]({})", url).dimmed().to_string());
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => self.newline(),
+            _ => {}
+        }
+    }
+
+    fn style(&self, text: &str) -> ColoredString {
+        let mut s = text.normal();
+        if let Some(level) = self.heading {
+            s = s.bold();
+            s = match level {
+                HeadingLevel::H1 => s.underline().magenta(),
+                HeadingLevel::H2 => s.magenta(),
+                _ => s.cyan(),
+            };
+        }
+        if self.strong > 0 {
+            s = s.bold();
+        }
+        if self.emphasis > 0 {
+            s = s.italic();
+        }
+        if self.strikethrough > 0 {
+            s = s.strikethrough();
+        }
+        if !self.links.is_empty() {
+            s = s.blue().underline();
+        }
+        s
+    }
+
+    /// Write styled text, highlighting mentions outside of links.
+    fn text(&mut self, text: &str) {
+        let mut lines = text.split('\n').peekable();
+        while let Some(line) = lines.next() {
+            if self.links.is_empty() && self.heading.is_none() {
+                let mut last = 0;
+                for caps in mention_regex().captures_iter(line) {
+                    if caps
+                        .name("team")
+                        .is_some_and(|key| !is_team_key(key.as_str()))
+                    {
+                        continue;
+                    }
+                    let m = caps.get(0).unwrap();
+                    let before = self.style(&line[last..m.start()]).to_string();
+                    self.write(&before);
+                    self.write(&m.as_str().cyan().bold().to_string());
+                    last = m.end();
+                }
+                let rest = self.style(&line[last..]).to_string();
+                self.write(&rest);
+            } else {
+                let styled = self.style(line).to_string();
+                self.write(&styled);
+            }
+            if lines.peek().is_some() {
+                self.newline();
+            }
+        }
+    }
+
+    fn code(&mut self, lang: &str, code: &str) {
+        let lines = highlight(code, lang)
+            .unwrap_or_else(|| code.lines().map(|line| line.green().to_string()).collect());
+        for line in lines {
+            self.ensure_line_start();
+            self.write("    ");
+            self.write(&line);
+            self.newline();
+        }
+    }
+
+    fn prefix(&self) -> String {
+        let mut prefix = String::new();
+        if self.quote_depth > 0 {
+            prefix.push_str(&"│ ".repeat(self.quote_depth).dimmed().to_string());
+        }
+        prefix.push_str(&" ".repeat(self.item_indents.iter().sum()));
+        prefix
+    }
+
+    fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if self.at_line_start {
+            let prefix = self.prefix();
+            self.out.push_str(&prefix);
+            self.at_line_start = false;
+        }
+        self.out.push_str(text);
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.at_line_start = true;
+    }
+
+    fn ensure_line_start(&mut self) {
+        if !self.out.is_empty() && !self.at_line_start {
+            self.newline();
+        }
+    }
+
+    fn block_start(&mut self) {
+        self.ensure_line_start();
+        if self.pending_blank {
+            // Continue quote bars on the empty line.
+            if self.quote_depth > 0 {
+                let bars = "│ ".repeat(self.quote_depth);
+                self.out.push_str(&bars.trim_end().dimmed().to_string());
+            }
+            self.newline();
+            self.pending_blank = false;
+        }
+    }
+
+    fn block_end(&mut self) {
+        self.ensure_line_start();
+        self.pending_blank = true;
+    }
+}
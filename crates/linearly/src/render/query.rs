@@ -0,0 +1,134 @@
+//! `--query`: jq filters applied to the JSON output of commands.
+
+use std::{ops::Range, sync::OnceLock};
+
+use anyhow::anyhow;
+use jaq_core::{
+    load::{self, Arena, File, Loader},
+    Compiler, Ctx, Filter, Native, RcIter,
+};
+use jaq_json::Val;
+use serde_json::Value;
+
+struct Active {
+    filter: Filter<Native<Val>>,
+    raw_output: bool,
+}
+
+static ACTIVE: OnceLock<Active> = OnceLock::new();
+
+/// Invalid jq filter.
+#[derive(Debug)]
+pub struct QueryError {
+    query: String,
+    span: Range<usize>,
+    message: String,
+}
+
+impl std::fmt::Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offset = self.query[..self.span.start].chars().count();
+        let width = self.query[self.span.clone()].chars().count();
+        writeln!(f, "invalid query: {}", self.message)?;
+        writeln!(f, "  {}", self.query)?;
+        write!(f, "  {}{}", " ".repeat(offset), "^".repeat(width.max(1)))
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// Apply the query to all JSON output of this process.
+///
+/// With `raw_output`, string results are printed without quotes.
+pub fn activate(code: &str, raw_output: bool) -> Result<(), QueryError> {
+    let filter = compile(code)?;
+    let _ = ACTIVE.set(Active { filter, raw_output });
+    Ok(())
+}
+
+pub fn is_active() -> bool {
+    ACTIVE.get().is_some()
+}
+
+/// Run the active query on a value, or `None` without a query.
+pub fn apply(value: Value) -> Result<Option<Vec<Value>>, anyhow::Error> {
+    let Some(active) = ACTIVE.get() else {
+        return Ok(None);
+    };
+    let inputs = RcIter::new(core::iter::empty());
+    active
+        .filter
+        .run((Ctx::new([], &inputs), Val::from(value)))
+        .map(|result| result.map(Value::from))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+        .map_err(|err| anyhow!("query failed: {}", err))
+}
+
+/// Format query results like jq: one result per line, pretty printed unless
+/// `compact`.
+pub fn format_results(results: &[Value], compact: bool) -> Result<String, anyhow::Error> {
+    let raw_output = ACTIVE.get().is_some_and(|a| a.raw_output);
+    let lines = results
+        .iter()
+        .map(|value| match value {
+            Value::String(s) if raw_output => Ok(s.clone()),
+            value if compact => serde_json::to_string(value),
+            value => serde_json::to_string_pretty(value),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(lines.join("\n"))
+}
+
+fn compile(code: &str) -> Result<Filter<Native<Val>>, QueryError> {
+    let error = |part: &str, message: String| QueryError {
+        query: code.to_string(),
+        span: load::span(code, part),
+        message,
+    };
+    // jaq reports at least one error, but point at the whole query otherwise.
+    let unknown = || error(code, "could not compile the query".to_string());
+
+    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
+    let arena = Arena::default();
+    let modules = loader
+        .load(&arena, File { code, path: () })
+        .map_err(|errors| {
+            let Some((_file, err)) = errors.into_iter().next() else {
+                return unknown();
+            };
+            match err {
+                // Modules are not supported, so point at the whole query.
+                load::Error::Io(errs) => match errs.into_iter().next() {
+                    Some((path, message)) => {
+                        error(code, format!("could not load module {}: {}", path, message))
+                    }
+                    None => unknown(),
+                },
+                load::Error::Lex(errs) => match errs.into_iter().next() {
+                    Some((expect, part)) => error(part, format!("expected {}", expect.as_str())),
+                    None => unknown(),
+                },
+                load::Error::Parse(errs) => match errs.into_iter().next() {
+                    Some((expect, part)) => error(part, format!("expected {}", expect.as_str())),
+                    None => unknown(),
+                },
+            }
+        })?;
+
+    Compiler::default()
+        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
+        .compile(modules)
+        .map_err(|errors| {
+            let undefined = errors
+                .into_iter()
+                .next()
+                .and_then(|(_file, errs)| errs.into_iter().next());
+            match undefined {
+                Some((name, undefined)) => {
+                    error(name, format!("undefined {} '{}'", undefined.as_str(), name))
+                }
+                None => unknown(),
+            }
+        })
+}
@@ -0,0 +1,76 @@
+//! Custom output with `--template`, using the minijinja (Jinja2) syntax.
+
+use anyhow::anyhow;
+use futures::Stream;
+use minijinja::{Environment, UndefinedBehavior, Value};
+use serde::Serialize;
+
+const NAME: &str = "template";
+
+/// A compiled output template.
+pub struct Template {
+    env: Environment<'static>,
+}
+
+impl Template {
+    pub fn new(source: &str) -> Result<Self, anyhow::Error> {
+        let mut env = Environment::new();
+        // Allow `{{ assignee.display_name }}` when there is no assignee.
+        env.set_undefined_behavior(UndefinedBehavior::Chainable);
+        env.add_template_owned(NAME, unescape(source))
+            .map_err(|err| anyhow!("invalid template: {}", err))?;
+        Ok(Self { env })
+    }
+
+    /// Render the template for a single item.
+    pub fn render<T: Serialize>(&self, item: &T) -> Result<String, anyhow::Error> {
+        let template = self.env.get_template(NAME)?;
+        template
+            .render(Value::from_serialize(item))
+            .map_err(|err| anyhow!("could not render template: {}", err))
+    }
+
+    /// Render the template for each item, one item per line.
+    pub fn render_list<T: Serialize>(&self, items: &[T]) -> Result<String, anyhow::Error> {
+        let lines = items
+            .iter()
+            .map(|item| self.render(item))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    /// Print the rendered template for the items of a stream as soon as they
+    /// arrive.
+    pub async fn print_stream<T, E, S>(&self, stream: S) -> Result<(), anyhow::Error>
+    where
+        T: Serialize,
+        E: std::error::Error + Send + Sync + 'static,
+        S: Stream<Item = Result<T, E>>,
+    {
+        super::stream_lines(stream, |item| self.render(item)).await
+    }
+}
+
+/// Replace the `\t`, `\n` and `\\` escapes, which shells don't expand in
+/// single quoted arguments.
+fn unescape(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
@@ -0,0 +1,604 @@
+//! Resolve human readable names given on the command line to Linear entities.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use cynic::QueryBuilder;
+
+use linear_api::{
+    schema::{
+        issue_get::{IssueGet, IssueGetVariables},
+        issue_list::{
+            BooleanComparator, Idcomparator, Issue, IssueLabelFilter, NumberComparator,
+            ProjectFilter, StringComparator, TeamFilter, UserFilter, WorkflowStateFilter,
+        },
+        lookup::{
+            CycleFilter, CycleRef, Cycles, CyclesVariables, IssueLabelRef, IssueLabels,
+            IssueLabelsVariables, ProjectRef, ProjectStatusRef, ProjectStatuses, Projects,
+            ProjectsVariables, TeamRef, Teams, TeamsVariables, Users, UsersVariables,
+            WorkflowStateRef, WorkflowStates, WorkflowStatesVariables,
+        },
+        viewer::QueryViewer,
+        User,
+    },
+    Client,
+};
+
+fn eq_ignore_case(value: &str) -> Option<StringComparator> {
+    Some(StringComparator {
+        eq_ignore_case: Some(value.to_string()),
+        ..Default::default()
+    })
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36 && value.chars().all(|c| c.is_ascii_hexdigit() || c == '-')
+}
+
+/// Filter matching a single team by ID.
+pub fn team_id_filter(team: &TeamRef) -> Option<Box<TeamFilter>> {
+    Some(Box::new(TeamFilter {
+        id: Some(Idcomparator {
+            eq: Some(team.id.clone()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+}
+
+/// List all teams.
+pub async fn teams(client: &Client) -> Result<Vec<TeamRef>, anyhow::Error> {
+    let vars = TeamsVariables { filter: None };
+    Ok(client.query(Teams::build(vars)).await?.teams.nodes)
+}
+
+/// Resolve a team by key (eg: MYTEAM) or name.
+pub async fn team(client: &Client, key: &str) -> Result<TeamRef, anyhow::Error> {
+    let filter = TeamFilter {
+        or: Some(vec![
+            TeamFilter {
+                key: eq_ignore_case(key),
+                ..Default::default()
+            },
+            TeamFilter {
+                name: eq_ignore_case(key),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    let vars = TeamsVariables {
+        filter: Some(filter),
+    };
+    let teams = client.query(Teams::build(vars)).await?.teams.nodes;
+    select_team(teams, key)
+}
+
+/// Pick the team with the given key, or else the only team with that name.
+fn select_team(mut teams: Vec<TeamRef>, key: &str) -> Result<TeamRef, anyhow::Error> {
+    if let Some(i) = teams.iter().position(|t| t.key.eq_ignore_ascii_case(key)) {
+        return Ok(teams.remove(i));
+    }
+    match teams.len() {
+        0 => bail!("team '{}' not found", key),
+        1 => Ok(teams.remove(0)),
+        _ => {
+            let names = teams
+                .iter()
+                .map(|t| format!("{} ({})", t.name, t.key))
+                .collect::<Vec<_>>()
+                .join(", ");
+            bail!("team '{}' is ambiguous, matches: {}", key, names)
+        }
+    }
+}
+
+/// Resolve a user by display name, full name or email.
+///
+/// "me" resolves to the current user.
+pub async fn user(client: &Client, name: &str) -> Result<User, anyhow::Error> {
+    if name == "me" {
+        return Ok(client.query(QueryViewer::build(())).await?.viewer);
+    }
+
+    let filter = UserFilter {
+        or: Some(vec![
+            UserFilter {
+                display_name: eq_ignore_case(name),
+                ..Default::default()
+            },
+            UserFilter {
+                name: eq_ignore_case(name),
+                ..Default::default()
+            },
+            UserFilter {
+                email: eq_ignore_case(name),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    let vars = UsersVariables {
+        filter: Some(filter),
+    };
+    let mut users = client.query(Users::build(vars)).await?.users.nodes;
+
+    match users.len() {
+        0 => bail!("user '{}' not found", name),
+        1 => Ok(users.remove(0)),
+        _ => {
+            let names = users
+                .iter()
+                .map(|u| format!("{} ({})", u.display_name, u.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            bail!("user '{}' is ambiguous, matches: {}", name, names)
+        }
+    }
+}
+
+/// List the workflow states of a team, ordered by position.
+pub async fn workflow_states(
+    client: &Client,
+    team: &TeamRef,
+) -> Result<Vec<WorkflowStateRef>, anyhow::Error> {
+    let filter = WorkflowStateFilter {
+        team: team_id_filter(team),
+        ..Default::default()
+    };
+    let vars = WorkflowStatesVariables {
+        filter: Some(filter),
+    };
+    let mut states = client
+        .query(WorkflowStates::build(vars))
+        .await?
+        .workflow_states
+        .nodes;
+    states.sort_by(|a, b| a.position.total_cmp(&b.position));
+    Ok(states)
+}
+
+/// Sort key for workflow state types, in the order issues move through them.
+pub fn state_type_order(kind: &str) -> usize {
+    [
+        "triage",
+        "backlog",
+        "unstarted",
+        "started",
+        "completed",
+        "canceled",
+    ]
+    .iter()
+    .position(|t| *t == kind)
+    .unwrap_or(usize::MAX)
+}
+
+/// Resolve a workflow state of a team by name.
+pub async fn workflow_state(
+    client: &Client,
+    team: &TeamRef,
+    name: &str,
+) -> Result<WorkflowStateRef, anyhow::Error> {
+    let states = workflow_states(client, team).await?;
+    find_workflow_state(&states, name)
+        .cloned()
+        .ok_or_else(|| unknown_state_error(team, name, &states))
+}
+
+pub fn find_workflow_state<'a>(
+    states: &'a [WorkflowStateRef],
+    name: &str,
+) -> Option<&'a WorkflowStateRef> {
+    states.iter().find(|s| s.name.eq_ignore_ascii_case(name))
+}
+
+fn unknown_state_error(team: &TeamRef, name: &str, states: &[WorkflowStateRef]) -> anyhow::Error {
+    let names = states
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    anyhow::anyhow!(
+        "team {} has no workflow state '{}' (available: {})",
+        team.key,
+        name,
+        names
+    )
+}
+
+/// Resolve label names.
+///
+/// Labels of the given team take precedence over workspace labels with the
+/// same name, and without a team workspace labels take precedence over team
+/// labels. Labels inside a group can be given as "Group/Label".
+pub async fn labels(
+    client: &Client,
+    team: Option<&TeamRef>,
+    names: &[String],
+) -> Result<Vec<IssueLabelRef>, anyhow::Error> {
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Query both interpretations of "a/b": a label called "a/b", and a
+    // label "b" in the group "a".
+    let filter = IssueLabelFilter {
+        or: Some(
+            names
+                .iter()
+                .flat_map(|name| {
+                    let child = name.split_once('/').map(|(_, label)| label.trim());
+                    std::iter::once(name.as_str()).chain(child)
+                })
+                .map(|name| IssueLabelFilter {
+                    name: eq_ignore_case(name),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    };
+    let vars = IssueLabelsVariables {
+        filter: Some(filter),
+    };
+    let candidates = client
+        .query(IssueLabels::build(vars))
+        .await?
+        .issue_labels
+        .nodes;
+
+    names
+        .iter()
+        .map(|name| select_label(&candidates, team, name).cloned())
+        .collect()
+}
+
+/// Pick the label called `name` among the candidates.
+///
+/// With a team, its labels take precedence over workspace labels, and labels
+/// of other teams are ignored. Without a team, workspace labels take
+/// precedence over team labels. Top-level labels take precedence over labels
+/// in a group.
+fn select_label<'a>(
+    candidates: &'a [IssueLabelRef],
+    team: Option<&TeamRef>,
+    name: &str,
+) -> Result<&'a IssueLabelRef, anyhow::Error> {
+    let matches = candidates
+        .iter()
+        .filter(|l| label_matches(l, name))
+        .filter_map(|l| {
+            let rank = match (team, &l.team) {
+                (Some(team), Some(own)) if own.id == team.id => 0,
+                (Some(_), Some(_)) => return None,
+                (Some(_), None) | (None, None) => 1,
+                (None, Some(_)) => 2,
+            };
+            Some(((rank, l.parent.is_some()), l))
+        })
+        .collect::<Vec<_>>();
+
+    let Some(best) = matches.iter().map(|(rank, _)| *rank).min() else {
+        bail!("label '{}' not found", name);
+    };
+    let mut matches = matches
+        .into_iter()
+        .filter(|(rank, _)| *rank == best)
+        .map(|(_, l)| l)
+        .collect::<Vec<_>>();
+
+    match matches.len() {
+        1 => Ok(matches.remove(0)),
+        _ => {
+            let names = matches
+                .iter()
+                .map(|l| {
+                    let name = match &l.parent {
+                        Some(parent) => format!("{}/{}", parent.name, l.name),
+                        None => l.name.clone(),
+                    };
+                    match &l.team {
+                        Some(team) => format!("{} ({})", name, team.key),
+                        None => format!("{} (workspace)", name),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            bail!("label '{}' is ambiguous, matches: {}", name, names)
+        }
+    }
+}
+
+/// Resolve "Group/Label" names to the IDs of all matching labels, in any
+/// team.
+pub async fn group_label_ids(
+    client: &Client,
+    names: &[String],
+) -> Result<HashMap<String, Vec<cynic::Id>>, anyhow::Error> {
+    if names.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let filter = IssueLabelFilter {
+        or: Some(
+            names
+                .iter()
+                .filter_map(|name| name.split_once('/'))
+                .map(|(_, label)| IssueLabelFilter {
+                    name: eq_ignore_case(label.trim()),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    };
+    let vars = IssueLabelsVariables {
+        filter: Some(filter),
+    };
+    let candidates = client
+        .query(IssueLabels::build(vars))
+        .await?
+        .issue_labels
+        .nodes;
+
+    Ok(names
+        .iter()
+        .map(|name| {
+            let ids = candidates
+                .iter()
+                .filter(|l| l.parent.is_some() && label_matches(l, name))
+                .map(|l| l.id.clone())
+                .collect();
+            (name.clone(), ids)
+        })
+        .collect())
+}
+
+/// Returns true if the label is called `name`, or `name` is "Group/Label"
+/// and matches the label and its group.
+fn label_matches(label: &IssueLabelRef, name: &str) -> bool {
+    if label.name.eq_ignore_ascii_case(name) {
+        return true;
+    }
+    match (name.split_once('/'), &label.parent) {
+        (Some((group, child)), Some(parent)) => {
+            parent.name.eq_ignore_ascii_case(group.trim())
+                && label.name.eq_ignore_ascii_case(child.trim())
+        }
+        _ => false,
+    }
+}
+
+/// Resolve a single label by name.
+pub async fn label(
+    client: &Client,
+    team: Option<&TeamRef>,
+    name: &str,
+) -> Result<IssueLabelRef, anyhow::Error> {
+    let mut labels = labels(client, team, &[name.to_string()]).await?;
+    Ok(labels.remove(0))
+}
+
+/// Resolve a project by name, slug ID or ID.
+pub async fn project(client: &Client, name: &str) -> Result<ProjectRef, anyhow::Error> {
+    let mut filters = vec![
+        ProjectFilter {
+            name: eq_ignore_case(name),
+            ..Default::default()
+        },
+        ProjectFilter {
+            slug_id: Some(StringComparator::eq(name.to_string())),
+            ..Default::default()
+        },
+    ];
+    if is_uuid(name) {
+        filters.push(ProjectFilter {
+            id: Some(Idcomparator {
+                eq: Some(cynic::Id::new(name)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+    let filter = ProjectFilter {
+        or: Some(filters),
+        ..Default::default()
+    };
+    let vars = ProjectsVariables {
+        filter: Some(filter),
+    };
+
+    client
+        .query(Projects::build(vars))
+        .await?
+        .projects
+        .nodes
+        .into_iter()
+        .next()
+        .with_context(|| format!("project '{}' not found", name))
+}
+
+/// Resolve a project status (eg: "In Progress") by name.
+pub async fn project_status(
+    client: &Client,
+    name: &str,
+) -> Result<ProjectStatusRef, anyhow::Error> {
+    let mut statuses = client
+        .query(ProjectStatuses::build(()))
+        .await?
+        .organization
+        .project_statuses;
+    statuses.sort_by(|a, b| a.position.total_cmp(&b.position));
+
+    if let Some(status) = statuses.iter().find(|s| s.name.eq_ignore_ascii_case(name)) {
+        return Ok(status.clone());
+    }
+    let names = statuses
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    bail!("unknown project status '{}' (available: {})", name, names)
+}
+
+/// Resolve a cycle of a team.
+///
+/// Accepts a cycle number, or one of "current", "next" and "previous".
+pub async fn cycle(client: &Client, team: &TeamRef, spec: &str) -> Result<CycleRef, anyhow::Error> {
+    let mut filter = CycleFilter {
+        team: team_id_filter(team),
+        ..Default::default()
+    };
+    match spec {
+        "current" | "active" => filter.is_active = Some(BooleanComparator::is_true()),
+        "next" => filter.is_next = Some(BooleanComparator::is_true()),
+        "previous" | "prev" => filter.is_previous = Some(BooleanComparator::is_true()),
+        number => {
+            let number: f64 = number.parse().with_context(|| {
+                format!(
+                    "invalid cycle '{}': expected a number, 'current', 'next' or 'previous'",
+                    spec
+                )
+            })?;
+            filter.number = Some(NumberComparator {
+                eq: Some(number),
+                ..Default::default()
+            });
+        }
+    }
+    let vars = CyclesVariables {
+        filter: Some(filter),
+    };
+
+    client
+        .query(Cycles::build(vars))
+        .await?
+        .cycles
+        .nodes
+        .into_iter()
+        .next()
+        .with_context(|| format!("cycle '{}' not found in team {}", spec, team.key))
+}
+
+/// Load an issue by identifier (eg: MYTEAM-123) or ID.
+pub async fn issue(client: &Client, id: &str) -> Result<Issue, anyhow::Error> {
+    let vars = IssueGetVariables { id: id.to_string() };
+    let issue = client
+        .query(IssueGet::build(vars))
+        .await
+        .with_context(|| format!("could not load issue {}", id))?
+        .issue;
+    Ok(issue)
+}
+
+#[cfg(test)]
+mod tests {
+    use linear_api::schema::lookup::IssueLabelParentRef;
+
+    use super::*;
+
+    fn team(key: &str) -> TeamRef {
+        TeamRef {
+            id: cynic::Id::new(key),
+            key: key.to_string(),
+            name: key.to_string(),
+        }
+    }
+
+    fn label(id: &str, name: &str, team: Option<&str>, parent: Option<&str>) -> IssueLabelRef {
+        IssueLabelRef {
+            id: cynic::Id::new(id),
+            name: name.to_string(),
+            color: "#000000".to_string(),
+            is_group: false,
+            team: team.map(self::team),
+            parent: parent.map(|name| IssueLabelParentRef {
+                id: cynic::Id::new(name),
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    fn select(candidates: &[IssueLabelRef], team: Option<&str>, name: &str) -> String {
+        match select_label(candidates, team.map(self::team).as_ref(), name) {
+            Ok(label) => label.id.inner().to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn team_key_wins_over_team_name() {
+        let mut named = team("OPS");
+        named.name = "Eng".to_string();
+        let teams = vec![named.clone(), team("ENG")];
+        assert_eq!(select_team(teams, "eng").unwrap().key, "ENG");
+        assert_eq!(select_team(vec![named.clone()], "eng").unwrap().key, "OPS");
+
+        let mut other = team("WEB");
+        other.name = "Eng".to_string();
+        assert_eq!(
+            select_team(vec![named, other], "Eng")
+                .unwrap_err()
+                .to_string(),
+            "team 'Eng' is ambiguous, matches: Eng (OPS), Eng (WEB)"
+        );
+        assert_eq!(
+            select_team(Vec::new(), "ENG").unwrap_err().to_string(),
+            "team 'ENG' not found"
+        );
+    }
+
+    #[test]
+    fn team_label_wins_over_workspace_label() {
+        let labels = [
+            label("workspace", "Bug", None, None),
+            label("eng", "Bug", Some("ENG"), None),
+            label("ops", "Bug", Some("OPS"), None),
+        ];
+        assert_eq!(select(&labels, Some("ENG"), "bug"), "eng");
+        assert_eq!(select(&labels, Some("WEB"), "bug"), "workspace");
+    }
+
+    #[test]
+    fn workspace_label_wins_without_team() {
+        let labels = [
+            label("eng", "Bug", Some("ENG"), None),
+            label("workspace", "Bug", None, None),
+        ];
+        assert_eq!(select(&labels, None, "Bug"), "workspace");
+    }
+
+    #[test]
+    fn team_labels_without_team_are_ambiguous() {
+        let labels = [
+            label("eng", "Bug", Some("ENG"), None),
+            label("ops", "Bug", Some("OPS"), None),
+        ];
+        assert_eq!(
+            select(&labels, None, "Bug"),
+            "label 'Bug' is ambiguous, matches: Bug (ENG), Bug (OPS)"
+        );
+        assert_eq!(select(&labels[..1], None, "Bug"), "eng");
+    }
+
+    #[test]
+    fn labels_of_other_teams_are_ignored() {
+        let labels = [label("ops", "Bug", Some("OPS"), None)];
+        assert_eq!(select(&labels, Some("ENG"), "Bug"), "label 'Bug' not found");
+    }
+
+    #[test]
+    fn group_labels() {
+        let labels = [
+            label("top", "Bug", None, None),
+            label("type", "Bug", None, Some("Type")),
+            label("area", "Bug", None, Some("Area")),
+        ];
+        assert_eq!(select(&labels, None, "Bug"), "top");
+        assert_eq!(select(&labels, None, "type/bug"), "type");
+        assert_eq!(
+            select(&labels[1..], None, "Bug"),
+            "label 'Bug' is ambiguous, matches: Type/Bug (workspace), Area/Bug (workspace)"
+        );
+    }
+}
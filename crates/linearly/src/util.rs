@@ -1 +1,103 @@
+use std::{
+    io::{IsTerminal, Read, Write},
+    path::Path,
+};
 
+use anyhow::{bail, Context};
+
+use linear_api::schema::{issue_list::TimelessDate, DateTime};
+
+/// Returns true if stdin and stderr are attached to a terminal, which means
+/// the user can be prompted for input.
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() && std::io::stderr().is_terminal()
+}
+
+/// Prompt the user for a single line of input.
+///
+/// Returns the trimmed input, which may be empty. Fails at the end of input
+/// (eg: Ctrl-D).
+pub fn prompt(label: &str) -> Result<String, anyhow::Error> {
+    eprint!("{}: ", label);
+    std::io::stderr().flush()?;
+    let mut buf = String::new();
+    if std::io::stdin().read_line(&mut buf)? == 0 {
+        eprintln!();
+        bail!("aborted: end of input");
+    }
+    Ok(buf.trim().to_string())
+}
+
+/// Prompt the user until a non-empty value is entered.
+pub fn prompt_required(label: &str) -> Result<String, anyhow::Error> {
+    loop {
+        let value = prompt(label)?;
+        if !value.is_empty() {
+            return Ok(value);
+        }
+    }
+}
+
+/// Prompt for an optional value, returning `None` if the input is empty.
+pub fn prompt_optional(label: &str) -> Result<Option<String>, anyhow::Error> {
+    let value = prompt(&format!("{} (optional)", label))?;
+    Ok(Some(value).filter(|v| !v.is_empty()))
+}
+
+/// Read the contents of a file, or of stdin if the path is "-".
+pub fn read_file_or_stdin(path: &Path) -> Result<String, anyhow::Error> {
+    if path == Path::new("-") {
+        let mut buf = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buf)
+            .context("could not read from stdin")?;
+        Ok(buf)
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("could not read file '{}'", path.display()))
+    }
+}
+
+/// Ask the user a yes/no question, defaulting to no.
+pub fn confirm(question: &str) -> Result<bool, anyhow::Error> {
+    let answer = prompt(&format!("{} [y/N]", question))?;
+    Ok(matches!(answer.to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Split an optional flag value into "not given" (`None`), "clear the field"
+/// (`Some(None)`, given as "none") and a new value.
+pub fn clearable(value: &Option<String>) -> Option<Option<&str>> {
+    value
+        .as_deref()
+        .map(|v| Some(v).filter(|v| !v.eq_ignore_ascii_case("none")))
+}
+
+/// Parse a date given as YYYY-MM-DD.
+pub fn parse_date(value: &str) -> Result<TimelessDate, anyhow::Error> {
+    let format = time::format_description::parse("[year]-[month]-[day]").unwrap();
+    time::Date::parse(value, &format)
+        .with_context(|| format!("invalid date '{}': expected YYYY-MM-DD", value))?;
+    Ok(TimelessDate(value.to_string()))
+}
+
+/// Parse a date given as YYYY-MM-DD into a timestamp at midnight UTC.
+pub fn parse_date_time(value: &str) -> Result<DateTime, anyhow::Error> {
+    let date = parse_date(value)?;
+    Ok(DateTime(format!("{}T00:00:00.000Z", date.0)))
+}
+
+/// Collect issue IDs given on the command line.
+///
+/// A "-" reads whitespace separated IDs from stdin.
+pub fn issue_ids(args: &[String]) -> Result<Vec<String>, anyhow::Error> {
+    let mut ids = Vec::new();
+    for id in args {
+        if id == "-" {
+            let input = read_file_or_stdin(id.as_ref())?;
+            ids.extend(input.split_whitespace().map(str::to_string));
+        } else {
+            ids.push(id.clone());
+        }
+    }
+    Ok(ids)
+}